@@ -5,19 +5,27 @@ use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 
 use ff::{Field, PrimeField};
-use pasta_curves::Fq;
+use pasta_curves::{arithmetic::SqrtRatio, Fq};
 
 fn criterion_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("Fq");
 
     group.bench_function("double", bench_fq_double);
+    group.bench_function("halve", bench_fq_halve);
     group.bench_function("add_assign", bench_fq_add_assign);
     group.bench_function("sub_assign", bench_fq_sub_assign);
     group.bench_function("mul_assign", bench_fq_mul_assign);
     group.bench_function("square", bench_fq_square);
+    group.bench_function("mul_self", bench_fq_mul_self);
     group.bench_function("invert", bench_fq_invert);
+    group.bench_function("invert_pow_vartime", bench_fq_invert_pow_vartime);
     group.bench_function("neg", bench_fq_neg);
     group.bench_function("sqrt", bench_fq_sqrt);
+    group.bench_function("pow_by_t_minus1_over2", bench_fq_pow_by_t_minus1_over2);
+    group.bench_function(
+        "pow_vartime_t_minus1_over2",
+        bench_fq_pow_vartime_t_minus1_over2,
+    );
     group.bench_function("to_repr", bench_fq_to_repr);
     group.bench_function("from_repr", bench_fq_from_repr);
 }
@@ -41,6 +49,25 @@ fn bench_fq_double(b: &mut Bencher) {
     });
 }
 
+fn bench_fq_halve(b: &mut Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::random(&mut rng)).collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        let mut tmp = v[count];
+        tmp = tmp.halve();
+        count = (count + 1) % SAMPLES;
+        tmp
+    });
+}
+
 fn bench_fq_add_assign(b: &mut Bencher) {
     const SAMPLES: usize = 1000;
 
@@ -123,6 +150,24 @@ fn bench_fq_square(b: &mut Bencher) {
     });
 }
 
+fn bench_fq_mul_self(b: &mut Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::random(&mut rng)).collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        let tmp = v[count] * v[count];
+        count = (count + 1) % SAMPLES;
+        tmp
+    });
+}
+
 fn bench_fq_invert(b: &mut Bencher) {
     const SAMPLES: usize = 1000;
 
@@ -140,6 +185,31 @@ fn bench_fq_invert(b: &mut Bencher) {
     });
 }
 
+fn bench_fq_invert_pow_vartime(b: &mut Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::random(&mut rng)).collect();
+
+    // p - 2, for the generic Fermat inversion.
+    let exp = [
+        0x8c46eb20ffffffff,
+        0x224698fc0994a8dd,
+        0x0000000000000000,
+        0x4000000000000000,
+    ];
+
+    let mut count = 0;
+    b.iter(|| {
+        count = (count + 1) % SAMPLES;
+        v[count].pow_vartime(&exp)
+    });
+}
+
 fn bench_fq_neg(b: &mut Bencher) {
     const SAMPLES: usize = 1000;
 
@@ -181,6 +251,40 @@ fn bench_fq_sqrt(b: &mut Bencher) {
     });
 }
 
+fn bench_fq_pow_by_t_minus1_over2(b: &mut Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::random(&mut rng)).collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        count = (count + 1) % SAMPLES;
+        v[count].pow_by_t_minus1_over2()
+    });
+}
+
+fn bench_fq_pow_vartime_t_minus1_over2(b: &mut Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::random(&mut rng)).collect();
+
+    let mut count = 0;
+    b.iter(|| {
+        count = (count + 1) % SAMPLES;
+        v[count].pow_vartime(&Fq::T_MINUS1_OVER2)
+    });
+}
+
 fn bench_fq_to_repr(b: &mut Bencher) {
     const SAMPLES: usize = 1000;
 
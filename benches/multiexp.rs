@@ -0,0 +1,48 @@
+//! Benchmarks for multi-scalar multiplication.
+//!
+//! Run with and without `--features multicore` to compare the serial and
+//! parallel implementations.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ff::Field;
+use group::{Curve, Group};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+use pasta_curves::arithmetic::{best_multiexp, CurveAffine};
+use pasta_curves::{pallas, vesta};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    multiexp_bench::<pallas::Affine>(c, "Pallas");
+    multiexp_bench::<vesta::Affine>(c, "Vesta");
+}
+
+fn multiexp_bench<C: CurveAffine>(c: &mut Criterion, name: &str) {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let max_k = 16;
+    let coeffs: Vec<_> = (0..(1 << max_k))
+        .map(|_| C::Scalar::random(&mut rng))
+        .collect();
+    let mut bases = vec![C::identity(); 1 << max_k];
+    let projective: Vec<_> = (0..(1 << max_k))
+        .map(|_| C::Curve::random(&mut rng))
+        .collect();
+    C::Curve::batch_normalize(&projective, &mut bases);
+
+    let mut group = c.benchmark_group(format!("{} multiexp", name));
+    group.sample_size(10);
+    for k in [8, 12, 16].iter() {
+        let n = 1 << k;
+        group.bench_function(BenchmarkId::from_parameter(k), |bencher| {
+            bencher.iter(|| best_multiexp(&coeffs[..n], &bases[..n]))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -2,6 +2,11 @@
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use ff::Field;
+use group::{Wnaf, WnafBase, WnafGroup, WnafScalar};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
 use pasta_curves::arithmetic::CurveExt;
 use pasta_curves::{pallas, vesta};
 
@@ -10,7 +15,7 @@ fn criterion_benchmark(c: &mut Criterion) {
     point_bench::<vesta::Point>(c, "Vesta");
 }
 
-fn point_bench<C: CurveExt>(c: &mut Criterion, name: &str) {
+fn point_bench<C: CurveExt + WnafGroup>(c: &mut Criterion, name: &str) {
     let mut group = c.benchmark_group(name);
 
     let a = C::generator();
@@ -31,6 +36,24 @@ fn point_bench<C: CurveExt>(c: &mut Criterion, name: &str) {
 
     group.bench_function("point to_affine", |bencher| bencher.iter(|| a.to_affine()));
 
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    let k = C::ScalarExt::random(&mut rng);
+    group.bench_function("point scalar multiplication", |bencher| {
+        bencher.iter(|| a * k)
+    });
+    group.bench_function("point wNAF scalar multiplication", |bencher| {
+        bencher.iter(|| Wnaf::new().scalar(&k).base(a))
+    });
+    let wnaf_base = WnafBase::<C, 5>::new(a);
+    let wnaf_scalar = WnafScalar::<C::ScalarExt, 5>::new(&k);
+    group.bench_function(
+        "point wNAF scalar multiplication (precomputed)",
+        |bencher| bencher.iter(|| &wnaf_base * &wnaf_scalar),
+    );
+
     for &n in [100, 1000, 10000].iter() {
         let input = vec![a; n];
         let mut output = vec![C::AffineRepr::default(); n];
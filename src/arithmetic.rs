@@ -5,10 +5,27 @@
 //! upstreamed into the `ff` and `group` crates after some refactoring.
 
 mod curves;
+mod fft;
 mod fields;
+#[cfg(feature = "alloc")]
+mod fixed_base;
+#[cfg(feature = "alloc")]
+mod multiexp;
+#[cfg(feature = "alloc")]
+mod poly;
 
 pub use curves::*;
+pub use fft::best_fft;
 pub use fields::*;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use fixed_base::FixedBaseTable;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use multiexp::best_multiexp;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use poly::{barycentric_eval, lagrange_basis_evals, lagrange_interpolate, DuplicatePoint};
 
 /// This represents an element of a group with basic operations that can be
 /// performed. This allows an FFT implementation (for example) to operate
@@ -1,13 +1,15 @@
 //! This module contains the `Curve`/`CurveAffine` abstractions that allow us to
 //! write code that generalizes over a pair of groups.
 
+#[cfg(feature = "alloc")]
+use ff::PrimeField;
 #[cfg(feature = "alloc")]
 use group::prime::{PrimeCurve, PrimeCurveAffine};
 #[cfg(feature = "alloc")]
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "alloc")]
-use super::{FieldExt, Group};
+use super::{FieldExt, FixedBaseTable, Group};
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
@@ -44,6 +46,10 @@ pub trait CurveExt:
 
     /// Apply the curve endomorphism by multiplying the x-coordinate
     /// by an element of multiplicative order 3.
+    ///
+    /// For the Pasta curves this maps $(x, y)$ to $(\zeta_B \cdot x, y)$, where
+    /// $\zeta_B$ is [`FieldExt::ZETA`] in the base field. This is equivalent to
+    /// multiplying the point by [`FieldExt::ZETA`] in the scalar field.
     fn endo(&self) -> Self;
 
     /// Return the Jacobian coordinates of this point.
@@ -116,17 +122,75 @@ pub trait CurveAffine:
 
     /// Obtains a point given $(x, y)$, failing if it is not on the
     /// curve.
+    ///
+    /// The Pasta curves have prime order, so every point on the curve is in the
+    /// prime-order subgroup and no separate subgroup check is needed; accordingly,
+    /// [`CofactorGroup::is_torsion_free`] always returns true.
+    ///
+    /// [`CofactorGroup::is_torsion_free`]: group::cofactor::CofactorGroup::is_torsion_free
     fn from_xy(x: Self::Base, y: Self::Base) -> CtOption<Self>;
 
     /// Returns whether or not this element is on the curve; should
     /// always be true unless an "unchecked" API was used.
+    ///
+    /// This checks the short Weierstrass equation $y^2 = x^3 + ax + b$, and
+    /// treats the encoding $(0, 0)$ as the identity.
     fn is_on_curve(&self) -> Choice;
 
+    /// Apply the curve endomorphism; see [`CurveExt::endo`].
+    ///
+    /// The default implementation goes through the projective form; curves
+    /// should override it to scale the x-coordinate directly.
+    fn endo(&self) -> Self {
+        self.to_curve().endo().into()
+    }
+
     /// Returns the curve constant $a$.
     fn a() -> Self::Base;
 
     /// Returns the curve constant $b$.
     fn b() -> Self::Base;
+
+    /// Precomputes a [`FixedBaseTable`] of multiples of this point, for fast
+    /// repeated scalar multiplication by it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not between 1 and 16.
+    fn precompute(&self, window: usize) -> FixedBaseTable<Self> {
+        FixedBaseTable::new(self, window)
+    }
+}
+
+/// Converts a scalar field element into the base field of the curve `C`, failing
+/// if its canonical integer value is not less than the base field modulus.
+///
+/// No modular reduction is performed: the result, if any, represents the same
+/// integer as `scalar`. For the Pasta cycle, the Pallas base field modulus $p$ is
+/// smaller than the Pallas scalar field modulus $q$, so this fails for Pallas
+/// scalars in the range $[p, q)$, and always succeeds for Vesta.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn base_from_scalar<C: CurveAffine>(scalar: &C::ScalarExt) -> CtOption<C::Base>
+where
+    C::Base: PrimeField<Repr = <C::ScalarExt as PrimeField>::Repr>,
+{
+    C::Base::from_repr(scalar.to_repr())
+}
+
+/// Converts a base field element into the scalar field of the curve `C`, failing
+/// if its canonical integer value is not less than the scalar field modulus.
+///
+/// No modular reduction is performed: the result, if any, represents the same
+/// integer as `base`. For the Pasta cycle, this always succeeds for Pallas, and
+/// fails for Vesta base field elements in the range $[p, q)$.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn scalar_from_base<C: CurveAffine>(base: &C::Base) -> CtOption<C::ScalarExt>
+where
+    C::ScalarExt: PrimeField<Repr = <C::Base as PrimeField>::Repr>,
+{
+    C::ScalarExt::from_repr(base.to_repr())
 }
 
 /// The affine coordinates of a point on an elliptic curve.
@@ -0,0 +1,177 @@
+//! Fast Fourier transforms over groups.
+
+use ff::{Field, PrimeField};
+
+use super::Group;
+
+/// Performs a radix-2 Fast-Fourier Transformation (FFT) on a vector of size
+/// $n = 2^k$, when provided `log_n` = $k$ and an element of multiplicative
+/// order $n$ called `omega` ($\omega$). The result is that the vector `a`, when
+/// interpreted as the coefficients of a polynomial of degree $n - 1$, is
+/// transformed into the evaluations of this polynomial at each of the $n$
+/// distinct powers of $\omega$. This transformation is invertible by providing
+/// $\omega^{-1}$ in place of $\omega$ and dividing each resulting field element
+/// by $n$.
+///
+/// The transformation is performed in place, using an iterative
+/// (Cooley-Tukey) algorithm.
+///
+/// # Panics
+///
+/// Panics if `log_n` exceeds the 2-adicity of the scalar field, or if the
+/// length of `a` is not $2^k$.
+#[allow(clippy::many_single_char_names)]
+pub fn best_fft<G: Group>(a: &mut [G], omega: G::Scalar, log_n: u32) {
+    fn bitreverse(mut n: usize, l: u32) -> usize {
+        let mut r = 0;
+        for _ in 0..l {
+            r = (r << 1) | (n & 1);
+            n >>= 1;
+        }
+        r
+    }
+
+    assert!(
+        log_n <= G::Scalar::S,
+        "log_n = {} exceeds the 2-adicity of the scalar field",
+        log_n
+    );
+    let n = a.len();
+    assert!(
+        n.is_power_of_two() && n.trailing_zeros() == log_n,
+        "input length {} is not 2^{}",
+        n,
+        log_n
+    );
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_n);
+        if k < rk {
+            a.swap(rk, k);
+        }
+    }
+
+    let mut m = 1;
+    for _ in 0..log_n {
+        let w_m = omega.pow_vartime(&[(n / (2 * m)) as u64, 0, 0, 0]);
+
+        let mut k = 0;
+        while k < n {
+            let mut w = G::Scalar::one();
+            for j in 0..m {
+                let mut t = a[k + j + m];
+                t.group_scale(&w);
+                a[k + j + m] = a[k + j];
+                a[k + j + m].group_sub(&t);
+                a[k + j].group_add(&t);
+                w *= &w_m;
+            }
+
+            k += 2 * m;
+        }
+
+        m *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use ff::{Field, PrimeField};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::best_fft;
+    use crate::Fp;
+
+    /// Returns an element of multiplicative order $2^k$.
+    fn omega<F: PrimeField>(k: u32) -> F {
+        let mut omega = F::root_of_unity();
+        for _ in k..F::S {
+            omega = omega.square();
+        }
+        omega
+    }
+
+    fn eval(poly: &[Fp], point: Fp) -> Fp {
+        poly.iter()
+            .rev()
+            .fold(Fp::zero(), |acc, coeff| acc * point + coeff)
+    }
+
+    #[test]
+    fn test_fft() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for k in 0..8 {
+            let n = 1 << k;
+            let omega: Fp = omega(k);
+            assert_eq!(omega.pow_vartime(&[n as u64]), Fp::one());
+
+            let poly: Vec<_> = (0..n).map(|_| Fp::random(&mut rng)).collect();
+
+            // The FFT evaluates the polynomial at each power of omega.
+            let mut evals = poly.clone();
+            best_fft(&mut evals, omega, k);
+            let mut point = Fp::one();
+            for e in evals.iter() {
+                assert_eq!(*e, eval(&poly, point));
+                point *= omega;
+            }
+
+            // Transforming with omega^{-1} and dividing by n recovers the coefficients.
+            let mut coeffs = evals;
+            best_fft(&mut coeffs, omega.invert().unwrap(), k);
+            let n_inv = Fp::from(n as u64).invert().unwrap();
+            for c in coeffs.iter_mut() {
+                *c *= n_inv;
+            }
+            assert_eq!(coeffs, poly);
+        }
+    }
+
+    #[test]
+    fn test_fft_points() {
+        use crate::pallas;
+        use group::Group;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // The FFT commutes with scalar multiplication by a fixed point.
+        let k = 4;
+        let omega: pallas::Scalar = omega(k);
+        let g = pallas::Point::generator();
+
+        let mut scalars: Vec<_> = (0..(1 << k))
+            .map(|_| pallas::Scalar::random(&mut rng))
+            .collect();
+        let mut points: Vec<_> = scalars.iter().map(|s| g * s).collect();
+
+        best_fft(&mut scalars, omega, k);
+        best_fft(&mut points, omega, k);
+        for (s, p) in scalars.iter().zip(points.iter()) {
+            assert_eq!(g * s, *p);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not 2^")]
+    fn test_fft_wrong_length() {
+        let mut a = [Fp::one(); 3];
+        best_fft(&mut a, omega(2), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the 2-adicity")]
+    fn test_fft_log_n_too_large() {
+        let mut a = [Fp::one()];
+        best_fft(&mut a, Fp::one(), Fp::S + 1);
+    }
+}
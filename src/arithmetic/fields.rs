@@ -1,6 +1,7 @@
 //! This module contains the `Field` abstraction that allows us to write
 //! code that generalizes over a pair of fields.
 
+use core::fmt;
 use core::mem::size_of;
 
 use static_assertions::const_assert;
@@ -11,12 +12,46 @@ use super::Group;
 use core::assert;
 
 #[cfg(feature = "sqrt-table")]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "sqrt-table")]
 use core::marker::PhantomData;
 
 const_assert!(size_of::<usize>() >= 4);
 
+/// The Legendre symbol of a field element, indicating whether it is a square.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegendreSymbol {
+    /// The element is zero.
+    Zero,
+    /// The element is a nonzero square.
+    QuadraticResidue,
+    /// The element is not a square.
+    QuadraticNonResidue,
+}
+
+/// An error returned by [`FieldExt::from_str_radix`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The radix was not 10 or 16.
+    UnsupportedRadix(u32),
+    /// The string contained no digits.
+    Empty,
+    /// The string contained a character that is not a digit in the radix.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnsupportedRadix(radix) => write!(f, "unsupported radix {}", radix),
+            ParseError::Empty => write!(f, "no digits to parse"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit {:?}", c),
+        }
+    }
+}
+
 /// A trait that exposes additional operations related to calculating square roots of
 /// prime-order finite fields.
 pub trait SqrtRatio: ff::PrimeField {
@@ -34,6 +69,29 @@ pub trait SqrtRatio: ff::PrimeField {
     /// canonically.
     fn get_lower_32(&self) -> u32;
 
+    /// Computes the Legendre symbol of this field element using Euler's criterion,
+    /// $\textsf{self}^{(p-1)/2}$.
+    ///
+    /// The exponentiation reuses [`Self::pow_by_t_minus1_over2`] followed by $S$
+    /// squarings, so this is cheaper than computing a square root when only
+    /// squareness is needed. The exponentiation is constant time, but the returned
+    /// enum is not.
+    fn legendre(&self) -> LegendreSymbol {
+        // (p - 1) / 2 = T * 2^(S - 1), and self^T = (self^((T - 1) / 2))^2 * self.
+        let mut res = self.pow_by_t_minus1_over2().square() * self;
+        for _ in 1..Self::S {
+            res = res.square();
+        }
+
+        if bool::from(res.is_zero()) {
+            LegendreSymbol::Zero
+        } else if res == Self::one() {
+            LegendreSymbol::QuadraticResidue
+        } else {
+            LegendreSymbol::QuadraticNonResidue
+        }
+    }
+
     /// Computes:
     ///
     /// - $(\textsf{true}, \sqrt{\textsf{num}/\textsf{div}})$, if $\textsf{num}$ and
@@ -89,6 +147,11 @@ pub trait SqrtRatio: ff::PrimeField {
     }
 
     /// Equivalent to `Self::sqrt_ratio(self, one())`.
+    ///
+    /// The returned [`Choice`] indicates whether `self` is a square. As with
+    /// [`SqrtRatio::sqrt_ratio`], the choice of root is unspecified; callers that need
+    /// a particular root (as point decompression does) should select it themselves,
+    /// for example using [`ff::PrimeField::is_odd`].
     fn sqrt_alt(&self) -> (Choice, Self) {
         Self::sqrt_ratio(self, &Self::one())
     }
@@ -103,7 +166,8 @@ pub trait FieldExt: SqrtRatio + From<bool> + Ord + Group<Scalar = Self> {
     /// Inverse of `PrimeField::root_of_unity()`
     const ROOT_OF_UNITY_INV: Self;
 
-    /// Generator of the $t-order$ multiplicative subgroup
+    /// Generator of the $t-order$ multiplicative subgroup, used to shift
+    /// evaluation domains onto a coset.
     const DELTA: Self;
 
     /// Inverse of $2$ in the field.
@@ -117,10 +181,63 @@ pub trait FieldExt: SqrtRatio + From<bool> + Ord + Group<Scalar = Self> {
 
     /// Obtains a field element that is congruent to the provided little endian
     /// byte representation of an integer.
+    ///
+    /// The 512-bit input is reduced modulo the field order. If the bytes are
+    /// uniformly random (e.g. a BLAKE2b-512 digest), the result is uniformly
+    /// distributed up to a statistical distance of less than $2^{-256}$, which
+    /// makes this suitable for deriving challenges and for hash-to-field.
     fn from_bytes_wide(bytes: &[u8; 64]) -> Self;
 
+    /// Obtains a field element from its canonical little endian bit
+    /// representation, the inverse of `PrimeFieldBits::to_le_bits`.
+    ///
+    /// Any number of bits may be given. Returns `None` if the integer they
+    /// represent is not less than the field order.
+    fn from_le_bits(bits: &[bool]) -> CtOption<Self> {
+        let mut repr = Self::Repr::default();
+        let mut overflow = Choice::from(0);
+        for (i, bit) in bits.iter().enumerate() {
+            match repr.as_mut().get_mut(i / 8) {
+                Some(byte) => *byte |= u8::from(*bit) << (i % 8),
+                None => overflow |= Choice::from(u8::from(*bit)),
+            }
+        }
+        Self::from_repr(repr).and_then(|f| CtOption::new(f, !overflow))
+    }
+
+    /// Parses a field element from a string of digits in base 10 or 16.
+    ///
+    /// A hexadecimal string may start with `0x`, and underscores are ignored. Values
+    /// that are not less than the field order are reduced modulo it. This is not
+    /// constant time.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let digits = match radix {
+            10 => s,
+            16 => s
+                .strip_prefix("0x")
+                .or_else(|| s.strip_prefix("0X"))
+                .unwrap_or(s),
+            _ => return Err(ParseError::UnsupportedRadix(radix)),
+        };
+
+        let base = Self::from(u64::from(radix));
+        let mut res = None;
+        for c in digits.chars().filter(|&c| c != '_') {
+            let digit = c.to_digit(radix).ok_or(ParseError::InvalidDigit(c))?;
+            res = Some(res.unwrap_or_else(Self::zero) * base + Self::from(u64::from(digit)));
+        }
+        res.ok_or(ParseError::Empty)
+    }
+
     /// Exponentiates `self` by `by`, where `by` is a little-endian order
     /// integer exponent.
+    ///
+    /// This operation is constant time with respect to both `self` and `by`: it
+    /// performs a squaring and a multiplication for every bit of the exponent,
+    /// and selects the result without branching. Use it whenever the base or
+    /// exponent is secret. If both are public, [`Field::pow_vartime`] is faster.
+    ///
+    /// [`Field::pow_vartime`]: ff::Field::pow_vartime
     fn pow(&self, by: &[u64; 4]) -> Self {
         let mut res = Self::one();
         for e in by.iter().rev() {
@@ -137,23 +254,66 @@ pub trait FieldExt: SqrtRatio + From<bool> + Ord + Group<Scalar = Self> {
     /// Gets the lower 128 bits of this field element when expressed
     /// canonically.
     fn get_lower_128(&self) -> u128;
+
+    /// Returns the multiplicative inverse of this element, or zero if it is zero.
+    ///
+    /// This runs in constant time, including with respect to whether the element
+    /// is zero.
+    fn invert_or_zero(&self) -> Self {
+        self.invert().unwrap_or(Self::zero())
+    }
+
+    /// Inverts every element of `inputs` in place using Montgomery's trick, which
+    /// costs a single field inversion and $3n$ multiplications.
+    ///
+    /// Zero elements are left untouched. This runs in constant time with respect to
+    /// which (if any) of the elements are zero.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn batch_invert(inputs: &mut [Self]) {
+        // Store the product of all previously seen nonzero elements.
+        let mut acc = Self::one();
+        let prefixes: Vec<Self> = inputs
+            .iter()
+            .map(|x| {
+                let prefix = acc;
+                // We will end up skipping all zeroes in inputs
+                acc = Self::conditional_select(&(acc * x), &acc, x.is_zero());
+                prefix
+            })
+            .collect();
+
+        // This is the inverse, as all the elements that are zero are skipped.
+        acc = acc.invert().unwrap();
+
+        for (x, prefix) in inputs.iter_mut().rev().zip(prefixes.iter().rev()) {
+            let skip = x.is_zero();
+
+            // Compute tmp = 1/x
+            let tmp = acc * prefix;
+
+            // Cancel out x in the denominator of `acc`
+            acc = Self::conditional_select(&(acc * *x), &acc, skip);
+
+            *x = Self::conditional_select(&tmp, x, skip);
+        }
+    }
 }
 
 /// Tonelli–Shanks' square-root algorithm for `p mod 16 = 1`.
 ///
 /// https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
 ///
-/// `tm1d2` should be set to `(t - 1) // 2`, where `t = (modulus - 1) >> F::S`.
+/// The exponentiation by `(t - 1) // 2`, where `t = (modulus - 1) >> F::S`, uses the
+/// field's [`SqrtRatio::pow_by_t_minus1_over2`] addition chain, and the loop runs a
+/// fixed number of iterations starting from the precomputed $2^S$ root of unity.
 #[cfg(not(feature = "sqrt-table"))]
 #[cfg_attr(docsrs, doc(cfg(not(feature = "sqrt-table"))))]
-pub(crate) fn sqrt_tonelli_shanks<F: ff::PrimeField, S: AsRef<[u64]>>(
-    f: &F,
-    tm1d2: S,
-) -> CtOption<F> {
+pub(crate) fn sqrt_tonelli_shanks<F: SqrtRatio>(f: &F) -> CtOption<F> {
     use subtle::ConstantTimeEq;
 
     // w = self^((t - 1) // 2)
-    let w = f.pow_vartime(tm1d2);
+    let w = f.pow_by_t_minus1_over2();
 
     let mut v = F::S;
     let mut x = w * f;
@@ -186,7 +346,7 @@ pub(crate) fn sqrt_tonelli_shanks<F: ff::PrimeField, S: AsRef<[u64]>>(
 
     CtOption::new(
         x,
-        (x * x).ct_eq(f), // Only return Some if it's the square root.
+        x.square().ct_eq(f), // Only return Some if it's the square root.
     )
 }
 
@@ -0,0 +1,132 @@
+//! Fixed-base scalar multiplication with precomputed tables.
+
+use alloc::vec::Vec;
+
+use ff::PrimeField;
+use group::{Curve, Group as _};
+use subtle::ConstantTimeEq;
+
+use super::CurveAffine;
+
+/// A table of precomputed multiples of a fixed base, for fast repeated scalar
+/// multiplication by that base.
+///
+/// The scalar is split into `window`-bit digits, and for each digit position $i$
+/// the table stores $j \cdot 2^{i \cdot w} \cdot B$ for every digit value $j$, so a
+/// multiplication costs one point addition per digit. The table holds
+/// $\lceil 255 / w \rceil \cdot 2^w$ affine points.
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<C: CurveAffine> {
+    window: usize,
+    table: Vec<C>,
+}
+
+impl<C: CurveAffine> FixedBaseTable<C> {
+    /// Precomputes the multiples of `base` for the given window size, in bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not between 1 and 16.
+    pub fn new(base: &C, window: usize) -> Self {
+        assert!((1..=16).contains(&window));
+
+        let windows = (C::Scalar::NUM_BITS as usize + window - 1) / window;
+
+        let mut projective = Vec::with_capacity(windows << window);
+        let mut window_base = base.to_curve();
+        for _ in 0..windows {
+            let mut acc = C::Curve::identity();
+            for _ in 0..(1 << window) {
+                projective.push(acc);
+                acc += window_base;
+            }
+            // acc is now 2^window times the base for this digit position.
+            window_base = acc;
+        }
+
+        let mut table = alloc::vec![C::identity(); projective.len()];
+        C::Curve::batch_normalize(&projective, &mut table);
+
+        FixedBaseTable { window, table }
+    }
+
+    /// Returns the window size, in bits, of this table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Multiplies the base by `scalar`.
+    ///
+    /// This is not constant time with respect to `scalar`. Table entries are
+    /// selected without branching, but the point additions take shortcuts when an
+    /// operand is the identity, which happens for every zero digit. It should only
+    /// be used with public scalars.
+    pub fn mul(&self, scalar: &C::Scalar) -> C::Curve {
+        let repr = scalar.to_repr();
+        let bytes = repr.as_ref();
+
+        let mut acc = C::Curve::identity();
+        for (i, multiples) in self.table.chunks(1 << self.window).enumerate() {
+            let digit = (0..self.window).fold(0u64, |digit, b| {
+                let bit = i * self.window + b;
+                let byte = bytes.get(bit / 8).copied().unwrap_or(0);
+                digit | (u64::from((byte >> (bit % 8)) & 1) << b)
+            });
+
+            let mut entry = C::identity();
+            for (j, multiple) in multiples.iter().enumerate() {
+                entry.conditional_assign(multiple, (j as u64).ct_eq(&digit));
+            }
+            acc += entry;
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::FixedBaseTable;
+    use crate::arithmetic::CurveAffine;
+    use crate::{pallas, vesta};
+
+    fn test_fixed_base<C: CurveAffine>() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let base = C::Curve::random(&mut rng).to_affine();
+        for &window in &[1, 4, 8] {
+            let table = base.precompute(window);
+            assert_eq!(table.window(), window);
+
+            for scalar in [C::Scalar::zero(), C::Scalar::one(), -C::Scalar::one()].iter() {
+                assert_eq!(table.mul(scalar), base * *scalar);
+            }
+            for _ in 0..20 {
+                let scalar = C::Scalar::random(&mut rng);
+                assert_eq!(table.mul(&scalar), base * scalar);
+            }
+        }
+
+        // A table for the identity only produces the identity.
+        let table = FixedBaseTable::new(&C::identity(), 4);
+        let scalar = C::Scalar::random(&mut rng);
+        assert!(bool::from(table.mul(&scalar).is_identity()));
+    }
+
+    #[test]
+    fn test_fixed_base_pallas() {
+        test_fixed_base::<pallas::Affine>();
+    }
+
+    #[test]
+    fn test_fixed_base_vesta() {
+        test_fixed_base::<vesta::Affine>();
+    }
+}
@@ -0,0 +1,238 @@
+//! Multi-scalar multiplication.
+
+use alloc::vec::Vec;
+
+use ff::PrimeField;
+use group::Group as _;
+
+use super::CurveAffine;
+
+/// Below this many terms, [`best_multiexp`] falls back to computing each
+/// scalar multiplication separately, which avoids the bucket setup cost.
+const SMALL_MULTIEXP: usize = 4;
+
+/// Performs a multi-scalar multiplication $\sum_i c_i \cdot B_i$ of the provided
+/// `bases` by the provided `coeffs`, using Pippenger's bucket method.
+///
+/// This is not constant time; it should only be used with public scalars.
+///
+/// With the `multicore` feature flag enabled, the inputs are split into one
+/// chunk per thread and the partial sums are computed in parallel.
+///
+/// # Panics
+///
+/// Panics if `coeffs` and `bases` have different lengths.
+pub fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    if bases.len() < SMALL_MULTIEXP {
+        return coeffs
+            .iter()
+            .zip(bases.iter())
+            .fold(C::Curve::identity(), |acc, (coeff, base)| {
+                acc + *base * *coeff
+            });
+    }
+
+    #[cfg(feature = "multicore")]
+    {
+        let num_threads = rayon::current_num_threads();
+        if coeffs.len() > num_threads {
+            let chunk = coeffs.len() / num_threads;
+            let num_chunks = coeffs.chunks(chunk).len();
+            let mut results = alloc::vec![C::Curve::identity(); num_chunks];
+            rayon::scope(|scope| {
+                for ((coeffs, bases), acc) in coeffs
+                    .chunks(chunk)
+                    .zip(bases.chunks(chunk))
+                    .zip(results.iter_mut())
+                {
+                    scope.spawn(move |_| {
+                        multiexp_serial(coeffs, bases, acc);
+                    });
+                }
+            });
+            return results
+                .iter()
+                .fold(C::Curve::identity(), |acc, result| acc + result);
+        }
+    }
+
+    let mut acc = C::Curve::identity();
+    multiexp_serial(coeffs, bases, &mut acc);
+    acc
+}
+
+/// Returns the window size, in bits, to use for a multiexp of `n` terms.
+///
+/// This approximates $\lceil \ln(n) \rceil$ without requiring floating-point
+/// support.
+fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // ln(n) = log2(n) * ln(2), and ln(2) ~= 0.693.
+        let log2 = (usize::BITS - n.leading_zeros()) as usize;
+        (log2 * 693 + 999) / 1000
+    }
+}
+
+/// Returns the `c`-bit window of the little-endian byte string `bytes`
+/// starting at bit `segment * c`.
+fn get_at(segment: usize, c: usize, bytes: &[u8]) -> usize {
+    let skip_bits = segment * c;
+    let skip_bytes = skip_bits / 8;
+
+    if skip_bytes >= bytes.len() {
+        return 0;
+    }
+
+    let mut v = [0u8; 8];
+    for (v, o) in v.iter_mut().zip(bytes[skip_bytes..].iter()) {
+        *v = *o;
+    }
+
+    let mut tmp = u64::from_le_bytes(v);
+    tmp >>= skip_bits - (skip_bytes * 8);
+    tmp %= 1 << c;
+
+    tmp as usize
+}
+
+pub(crate) fn multiexp_serial<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    acc: &mut C::Curve,
+) {
+    let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+
+    let c = window_size(bases.len());
+    let segments = (C::Scalar::NUM_BITS as usize + c - 1) / c;
+
+    let mut buckets: Vec<C::Curve> = Vec::with_capacity((1 << c) - 1);
+
+    for current_segment in (0..segments).rev() {
+        for _ in 0..c {
+            *acc = acc.double();
+        }
+
+        buckets.clear();
+        buckets.resize((1 << c) - 1, C::Curve::identity());
+
+        for (coeff, base) in coeffs.iter().zip(bases.iter()) {
+            let coeff = get_at(current_segment, c, coeff.as_ref());
+            if coeff != 0 {
+                buckets[coeff - 1] += base;
+            }
+        }
+
+        // Summation by parts
+        // e.g. 3a + 2b + 1c = a +
+        //                    (a) + b +
+        //                    ((a) + b) + c
+        let mut running_sum = C::Curve::identity();
+        for exp in buckets.iter().rev() {
+            running_sum += exp;
+            *acc += &running_sum;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::best_multiexp;
+    use crate::{pallas, vesta};
+
+    fn naive_multiexp<C: super::CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+        coeffs
+            .iter()
+            .zip(bases.iter())
+            .fold(C::Curve::identity(), |acc, (coeff, base)| {
+                acc + *base * *coeff
+            })
+    }
+
+    fn test_multiexp<C: super::CurveAffine>() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for &n in &[0, 1, 3, 4, 31, 32, 100] {
+            let coeffs: Vec<_> = (0..n).map(|_| C::Scalar::random(&mut rng)).collect();
+            let bases: Vec<C> = (0..n)
+                .map(|_| C::Curve::random(&mut rng).to_affine())
+                .collect();
+
+            assert_eq!(
+                best_multiexp(&coeffs, &bases),
+                naive_multiexp(&coeffs, &bases)
+            );
+        }
+
+        // Zero coefficients and identity bases contribute nothing.
+        let coeffs = [
+            C::Scalar::zero(),
+            C::Scalar::one(),
+            -C::Scalar::one(),
+            C::Scalar::zero(),
+            C::Scalar::one(),
+        ];
+        let bases = [
+            C::generator(),
+            C::identity(),
+            C::generator(),
+            C::generator(),
+            C::generator(),
+        ];
+        assert_eq!(best_multiexp(&coeffs, &bases), C::Curve::identity());
+    }
+
+    #[cfg(feature = "multicore")]
+    fn test_multiexp_parallel<C: super::CurveAffine>() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for &n in &[5, 100, 1000] {
+            let coeffs: Vec<_> = (0..n).map(|_| C::Scalar::random(&mut rng)).collect();
+            let bases: Vec<C> = (0..n)
+                .map(|_| C::Curve::random(&mut rng).to_affine())
+                .collect();
+
+            let mut serial = C::Curve::identity();
+            super::multiexp_serial(&coeffs, &bases, &mut serial);
+            let parallel = best_multiexp(&coeffs, &bases);
+
+            assert_eq!(
+                parallel.to_affine().to_bytes().as_ref(),
+                serial.to_affine().to_bytes().as_ref()
+            );
+        }
+    }
+
+    #[cfg(feature = "multicore")]
+    #[test]
+    fn test_multiexp_parallel_matches_serial() {
+        test_multiexp_parallel::<pallas::Affine>();
+        test_multiexp_parallel::<vesta::Affine>();
+    }
+
+    #[test]
+    fn test_multiexp_pallas() {
+        test_multiexp::<pallas::Affine>();
+    }
+
+    #[test]
+    fn test_multiexp_vesta() {
+        test_multiexp::<vesta::Affine>();
+    }
+}
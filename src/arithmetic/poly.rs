@@ -0,0 +1,337 @@
+//! Polynomial interpolation and evaluation.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::FieldExt;
+
+/// An error returned by [`lagrange_interpolate`] when two of the given points
+/// are equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DuplicatePoint<F> {
+    /// The point that appears more than once.
+    pub point: F,
+    /// The index of its first occurrence in `points`.
+    pub first: usize,
+    /// The index of its second occurrence in `points`.
+    pub second: usize,
+}
+
+impl<F: fmt::Debug> fmt::Display for DuplicatePoint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "duplicate point {:?} at indices {} and {}",
+            self.point, self.first, self.second
+        )
+    }
+}
+
+/// Returns the coefficients, in increasing order of degree, of the unique
+/// polynomial of degree less than $n$ that takes the value `evals[i]` at
+/// `points[i]` for each of the $n$ given points.
+///
+/// Returns a [`DuplicatePoint`] error naming the point if any two of the
+/// `points` are equal, in which case no such polynomial need exist. A single
+/// point yields a constant polynomial, and no points yield the zero polynomial
+/// with no coefficients.
+///
+/// This is not constant time.
+///
+/// # Panics
+///
+/// Panics if `points` and `evals` have different lengths.
+pub fn lagrange_interpolate<F: FieldExt>(
+    points: &[F],
+    evals: &[F],
+) -> Result<Vec<F>, DuplicatePoint<F>> {
+    assert_eq!(points.len(), evals.len());
+
+    match points.len() {
+        // Zero polynomial
+        0 => return Ok(Vec::new()),
+        // Constant polynomial
+        1 => return Ok(alloc::vec![evals[0]]),
+        _ => (),
+    }
+
+    // Compute the denominators 1 / (x_j - x_k) of each Lagrange basis
+    // polynomial, failing if any two points coincide.
+    let mut denoms = Vec::with_capacity(points.len() * (points.len() - 1));
+    for (j, x_j) in points.iter().enumerate() {
+        for (k, x_k) in points.iter().enumerate() {
+            if k != j {
+                let denom = *x_j - x_k;
+                if bool::from(denom.is_zero()) {
+                    // The first collision found has k > j, since any earlier
+                    // index would have been reported when it was x_j.
+                    return Err(DuplicatePoint {
+                        point: *x_j,
+                        first: j,
+                        second: k,
+                    });
+                }
+                denoms.push(denom);
+            }
+        }
+    }
+    F::batch_invert(&mut denoms);
+
+    let mut final_poly = alloc::vec![F::zero(); points.len()];
+    for ((j, eval), denoms) in evals
+        .iter()
+        .enumerate()
+        .zip(denoms.chunks(points.len() - 1))
+    {
+        // Build the j-th basis polynomial \prod_{k != j} (X - x_k) / (x_j - x_k).
+        let mut tmp: Vec<F> = Vec::with_capacity(points.len());
+        let mut product = Vec::with_capacity(points.len());
+        tmp.push(F::one());
+        for (x_k, denom) in points
+            .iter()
+            .enumerate()
+            .filter(|&(k, _)| k != j)
+            .map(|(_, x_k)| x_k)
+            .zip(denoms.iter())
+        {
+            product.resize(tmp.len() + 1, F::zero());
+            for ((a, b), product) in tmp
+                .iter()
+                .chain(core::iter::once(&F::zero()))
+                .zip(core::iter::once(&F::zero()).chain(tmp.iter()))
+                .zip(product.iter_mut())
+            {
+                *product = *a * (-*denom * x_k) + *b * denom;
+            }
+            core::mem::swap(&mut tmp, &mut product);
+        }
+        assert_eq!(tmp.len(), points.len());
+        for (final_coeff, interpolation_coeff) in final_poly.iter_mut().zip(tmp) {
+            *final_coeff += interpolation_coeff * eval;
+        }
+    }
+    Ok(final_poly)
+}
+
+/// Evaluates at `z` the polynomial of degree less than $n$ whose evaluations at
+/// the powers $\omega^0, \ldots, \omega^{n-1}$ of `omega` are `evals`, where $n$
+/// is the length of `evals`.
+///
+/// This uses the barycentric formula
+/// $p(z) = \frac{z^n - 1}{n} \sum_i \frac{e_i \cdot \omega^i}{z - \omega^i}$,
+/// which costs $O(n)$ operations and a single inversion, rather than recovering
+/// the coefficients with an inverse FFT. If `z` is one of the powers of `omega`,
+/// the corresponding element of `evals` is returned.
+///
+/// `omega` must have multiplicative order $n$, such as an element returned from
+/// repeatedly squaring [`ff::PrimeField::root_of_unity`] when $n$ is a power of
+/// two. This is not constant time.
+pub fn barycentric_eval<F: FieldExt>(evals: &[F], omega: F, z: F) -> F {
+    let n = evals.len();
+    if n == 0 {
+        return F::zero();
+    }
+
+    let mut denoms = Vec::with_capacity(n);
+    let mut omega_i = F::one();
+    for eval in evals.iter() {
+        if omega_i == z {
+            return *eval;
+        }
+        denoms.push(z - omega_i);
+        omega_i *= omega;
+    }
+    F::batch_invert(&mut denoms);
+
+    let mut sum = F::zero();
+    let mut omega_i = F::one();
+    for (eval, denom_inv) in evals.iter().zip(denoms.iter()) {
+        sum += *eval * omega_i * denom_inv;
+        omega_i *= omega;
+    }
+
+    let n_inv = F::from(n as u64).invert().unwrap();
+    (z.pow_vartime(&[n as u64]) - F::one()) * n_inv * sum
+}
+
+/// Evaluates at `x` the Lagrange basis polynomials $L_i$ of the multiplicative
+/// subgroup of order $n$ generated by `omega`, for each of the given `indices`.
+///
+/// $L_i$ is the polynomial of degree less than $n$ that is $1$ at $\omega^i$ and
+/// $0$ at every other power of $\omega$. It is computed with the barycentric
+/// identity $L_i(x) = \frac{\omega^i (x^n - 1)}{n (x - \omega^i)}$, using a
+/// single batched inversion. Negative indices use powers of $\omega^{-1}$, so
+/// $L_{-1}$ is $L_{n-1}$. If `x` is in the subgroup, the results are $0$ or $1$.
+///
+/// `omega` must have multiplicative order $n$. This is not constant time.
+pub fn lagrange_basis_evals<F: FieldExt>(
+    omega: F,
+    n: usize,
+    x: F,
+    indices: impl IntoIterator<Item = i32>,
+) -> Vec<F> {
+    let omega_inv = omega.invert().unwrap();
+    let omega_i: Vec<F> = indices
+        .into_iter()
+        .map(|i| {
+            let base = if i < 0 { omega_inv } else { omega };
+            base.pow_vartime(&[u64::from(i.unsigned_abs())])
+        })
+        .collect();
+
+    let xn_minus_one = x.pow_vartime(&[n as u64]) - F::one();
+    if bool::from(xn_minus_one.is_zero()) {
+        // x is in the subgroup, where the basis polynomials are indicators.
+        return omega_i.iter().map(|w| F::from(*w == x)).collect();
+    }
+
+    let mut denoms: Vec<F> = omega_i.iter().map(|w| x - w).collect();
+    F::batch_invert(&mut denoms);
+
+    let common = xn_minus_one * F::from(n as u64).invert().unwrap();
+    omega_i
+        .iter()
+        .zip(denoms.iter())
+        .map(|(w, denom_inv)| common * w * denom_inv)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use ff::{Field, PrimeField};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::{barycentric_eval, lagrange_basis_evals, lagrange_interpolate, DuplicatePoint};
+    use crate::arithmetic::best_fft;
+    use crate::Fp;
+
+    fn eval(poly: &[Fp], point: Fp) -> Fp {
+        poly.iter()
+            .rev()
+            .fold(Fp::zero(), |acc, coeff| acc * point + coeff)
+    }
+
+    #[test]
+    fn test_lagrange_interpolate() {
+        // 3 + 2x - x^2 + 5x^3
+        let cubic = [Fp::from(3), Fp::from(2), -Fp::one(), Fp::from(5)];
+
+        let points: [Fp; 4] = [Fp::from(1), Fp::from(2), -Fp::from(7), Fp::from(1000)];
+        let evals = points.map(|x| eval(&cubic, x));
+        let poly = lagrange_interpolate(&points, &evals).unwrap();
+        assert_eq!(poly, cubic);
+        for (x, y) in points.iter().zip(evals.iter()) {
+            assert_eq!(eval(&poly, *x), *y);
+        }
+
+        // Extra points give the same polynomial, with a zero leading coefficient.
+        let points: [Fp; 5] = [0, 1, 2, 3, 4].map(Fp::from);
+        let evals = points.map(|x| eval(&cubic, x));
+        let poly = lagrange_interpolate(&points, &evals).unwrap();
+        assert_eq!(poly[..4], cubic);
+        assert_eq!(poly[4], Fp::zero());
+
+        // Degenerate cases.
+        assert_eq!(
+            lagrange_interpolate(&[Fp::from(9)], &[Fp::from(5)]).unwrap(),
+            [Fp::from(5)]
+        );
+        assert!(lagrange_interpolate::<Fp>(&[], &[]).unwrap().is_empty());
+
+        // Duplicate points are rejected.
+        let points = [Fp::from(1), Fp::from(2), Fp::from(1)];
+        let evals = [Fp::from(3), Fp::from(4), Fp::from(5)];
+        assert_eq!(
+            lagrange_interpolate(&points, &evals),
+            Err(DuplicatePoint {
+                point: Fp::from(1),
+                first: 0,
+                second: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_barycentric_eval() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for k in 0..6 {
+            let n = 1 << k;
+            let mut omega = Fp::root_of_unity();
+            for _ in k..Fp::S {
+                omega = omega.square();
+            }
+
+            let poly: Vec<_> = (0..n).map(|_| Fp::random(&mut rng)).collect();
+            let mut evals = poly.clone();
+            best_fft(&mut evals, omega, k);
+
+            // Out-of-domain points agree with evaluating the coefficients.
+            for _ in 0..5 {
+                let z = Fp::random(&mut rng);
+                assert_eq!(barycentric_eval(&evals, omega, z), eval(&poly, z));
+            }
+
+            // Points in the domain return the stored evaluations.
+            let mut omega_i = Fp::one();
+            for e in evals.iter() {
+                assert_eq!(barycentric_eval(&evals, omega, omega_i), *e);
+                omega_i *= omega;
+            }
+        }
+
+        assert_eq!(barycentric_eval(&[], Fp::one(), Fp::from(3)), Fp::zero());
+    }
+
+    #[test]
+    fn test_lagrange_basis_evals() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let k = 4;
+        let n = 1 << k;
+        let mut omega = Fp::root_of_unity();
+        for _ in k..Fp::S {
+            omega = omega.square();
+        }
+
+        // In the domain, L_i(omega^j) is 1 if i = j and 0 otherwise.
+        let mut omega_j = Fp::one();
+        for j in 0..n {
+            let evals = lagrange_basis_evals(omega, n, omega_j, 0..n as i32);
+            for (i, eval) in evals.iter().enumerate() {
+                assert_eq!(*eval, Fp::from(i == j));
+            }
+            omega_j *= omega;
+        }
+
+        for _ in 0..5 {
+            let x = Fp::random(&mut rng);
+            let evals = lagrange_basis_evals(omega, n, x, 0..n as i32);
+
+            // The basis polynomials sum to 1.
+            assert_eq!(evals.iter().sum::<Fp>(), Fp::one());
+
+            // L_i(x) matches evaluating the i-th unit vector.
+            for (i, eval) in evals.iter().enumerate() {
+                let mut unit = vec![Fp::zero(); n];
+                unit[i] = Fp::one();
+                assert_eq!(barycentric_eval(&unit, omega, x), *eval);
+            }
+
+            // Negative indices wrap around the domain.
+            assert_eq!(
+                lagrange_basis_evals(omega, n, x, [-1, -(n as i32)]),
+                [evals[n - 1], evals[0]]
+            );
+        }
+    }
+}
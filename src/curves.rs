@@ -1,8 +1,6 @@
 //! This module contains implementations for the Pallas and Vesta elliptic curve
 //! groups.
 
-use serde::{Serialize, Deserialize};
-
 use core::cmp;
 use core::fmt;
 use core::iter::Sum;
@@ -21,16 +19,16 @@ use rand::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use super::{Fp, Fq};
-use crate::arithmetic::Group;
+use crate::arithmetic::{mac, FieldExt, Group};
 
 #[cfg(feature = "alloc")]
-use crate::arithmetic::{Coordinates, CurveAffine, CurveExt, FieldExt};
+use crate::arithmetic::{Coordinates, CurveAffine, CurveExt};
 
 macro_rules! new_curve_impl {
     (($($privacy:tt)*), $name:ident, $name_affine:ident, $iso:ident, $base:ident, $scalar:ident,
      $curve_id:literal, $a_raw:expr, $b_raw:expr, $curve_type:ident) => {
         /// Represents a point in the projective coordinate space.
-        #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+        #[derive(Copy, Clone, Debug)]
         #[cfg_attr(feature = "repr-c", repr(C))]
         $($privacy)* struct $name {
             x: $base,
@@ -50,7 +48,11 @@ macro_rules! new_curve_impl {
 
         /// Represents a point in the affine coordinate space (or the point at
         /// infinity).
-        #[derive(Copy, Clone, Serialize, Deserialize)]
+        ///
+        /// The point at infinity is represented by the coordinates $(0, 0)$, which
+        /// do not satisfy the curve equation because $b \neq 0$, and is encoded as
+        /// 32 zero bytes.
+        #[derive(Copy, Clone)]
         #[cfg_attr(feature = "repr-c", repr(C))]
         $($privacy)* struct $name_affine {
             x: $base,
@@ -331,6 +333,7 @@ macro_rules! new_curve_impl {
             type Output = $name;
 
             fn neg(self) -> $name {
+                // The identity has z = 0, so this maps it to itself.
                 $name {
                     x: self.x,
                     y: -self.y,
@@ -499,6 +502,7 @@ macro_rules! new_curve_impl {
             type Output = $name_affine;
 
             fn neg(self) -> $name_affine {
+                // The identity is (0, 0), so this maps it to itself.
                 $name_affine {
                     x: self.x,
                     y: -self.y,
@@ -691,16 +695,12 @@ macro_rules! new_curve_impl {
             }
 
             fn to_bytes(&self) -> [u8; 32] {
-                // TODO: not constant time
-                if bool::from(self.is_identity()) {
-                    [0; 32]
-                } else {
-                    let (x, y) = (self.x, self.y);
-                    let sign = y.is_odd().unwrap_u8() << 7;
-                    let mut xbytes = x.to_repr();
-                    xbytes[31] |= sign;
-                    xbytes
-                }
+                // The identity is represented as (0, 0), so it is encoded as all
+                // zeroes without needing to branch on it.
+                let sign = self.y.is_odd().unwrap_u8() << 7;
+                let mut xbytes = self.x.to_repr();
+                xbytes[31] |= sign;
+                xbytes
             }
         }
 
@@ -735,6 +735,8 @@ macro_rules! new_curve_impl {
             fn b() -> Self::Base {
                 $name::curve_constant_b()
             }
+
+            impl_affine_curve_ext!($name_affine, $base, $curve_type);
         }
 
         impl Default for $name_affine {
@@ -933,6 +935,22 @@ macro_rules! impl_projective_curve_ext {
     };
 }
 
+#[cfg(feature = "alloc")]
+macro_rules! impl_affine_curve_ext {
+    ($name_affine:ident, $base:ident, special_a0_b5) => {
+        /// Apply the curve endomorphism by multiplying the x-coordinate
+        /// by an element of multiplicative order 3.
+        fn endo(&self) -> Self {
+            $name_affine {
+                x: self.x * $base::ZETA,
+                y: self.y,
+            }
+        }
+    };
+    // Other curves use the default `CurveAffine::endo`.
+    ($name_affine:ident, $base:ident, general) => {};
+}
+
 macro_rules! impl_affine_curve_specific {
     ($name:ident, $base:ident, special_a0_b5) => {
         fn generator() -> Self {
@@ -1015,6 +1033,56 @@ new_curve_impl!(
 );
 
 impl Ep {
+    /// The first vector $(a_1, b_1)$ of a short basis of the GLV lattice
+    /// $\{(a, b) : a + b \zeta \equiv 0 \pmod q\}$, where
+    /// $\zeta$ = `Fq::ZETA`.
+    pub const GLV_A1: Fq = Fq::from_raw([
+        0x7fcae1c700000001,
+        0x49e69d1640f04915,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// See [`Self::GLV_A1`].
+    pub const GLV_B1: Fq = Fq::neg(&Fq::from_raw([
+        0x8cb1279300000000,
+        0x49e69d1640a89953,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    /// The second vector $(a_2, b_2)$ of a short basis of the GLV lattice.
+    pub const GLV_A2: Fq = Fq::from_raw([
+        0x8cb1279300000000,
+        0x49e69d1640a89953,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// See [`Self::GLV_A2`].
+    pub const GLV_B2: Fq = Fq::from_raw([
+        0x0c7c095a00000001,
+        0x93cd3a2c8198e269,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// $\lfloor 2^{256} b_2 / q \rceil$, used to decompose scalars for [`Self::mul_glv`].
+    const GLV_G1: [u64; 4] = [
+        0x31f0256800000003,
+        0x4f34e8b2066389a4,
+        0x0000000000000002,
+        0x0000000000000000,
+    ];
+
+    /// $\lfloor -2^{256} b_1 / q \rceil$, used to decompose scalars for [`Self::mul_glv`].
+    const GLV_G2: [u64; 4] = [
+        0x32c49e4bffffffff,
+        0x279a745902a2654e,
+        0x0000000000000001,
+        0x0000000000000000,
+    ];
+
     /// Constants used for computing the isogeny from IsoEp to Ep.
     pub const ISOGENY_CONSTANTS: [Fp; 13] = [
         Fp::from_raw([
@@ -1115,6 +1183,56 @@ impl Ep {
 }
 
 impl Eq {
+    /// The first vector $(a_1, b_1)$ of a short basis of the GLV lattice
+    /// $\{(a, b) : a + b \zeta \equiv 0 \pmod p\}$, where
+    /// $\zeta$ = `Fp::ZETA`.
+    pub const GLV_A1: Fp = Fp::from_raw([
+        0x7fcae1c700000000,
+        0x49e69d1640f04915,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// See [`Self::GLV_A1`].
+    pub const GLV_B1: Fp = Fp::neg(&Fp::from_raw([
+        0x8cb1279300000001,
+        0x49e69d1640a89953,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]));
+
+    /// The second vector $(a_2, b_2)$ of a short basis of the GLV lattice.
+    pub const GLV_A2: Fp = Fp::from_raw([
+        0x8cb1279300000001,
+        0x49e69d1640a89953,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// See [`Self::GLV_A2`].
+    pub const GLV_B2: Fp = Fp::from_raw([
+        0x0c7c095a00000001,
+        0x93cd3a2c8198e269,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// $\lfloor 2^{256} b_2 / p \rceil$, used to decompose scalars for [`Self::mul_glv`].
+    const GLV_G1: [u64; 4] = [
+        0x31f0256800000003,
+        0x4f34e8b2066389a4,
+        0x0000000000000002,
+        0x0000000000000000,
+    ];
+
+    /// $\lfloor -2^{256} b_1 / p \rceil$, used to decompose scalars for [`Self::mul_glv`].
+    const GLV_G2: [u64; 4] = [
+        0x32c49e4c00000003,
+        0x279a745902a2654e,
+        0x0000000000000001,
+        0x0000000000000000,
+    ];
+
     /// Constants used for computing the isogeny from IsoEq to Eq.
     pub const ISOGENY_CONSTANTS: [Fq; 13] = [
         Fq::from_raw([
@@ -1213,3 +1331,111 @@ impl Eq {
         0x2b3483a1ee9a382f,
     ]);
 }
+
+/// Computes `(a * b) >> 256`, where `a` and `b` are little-endian 256-bit
+/// integers.
+fn mul_shift_256(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut wide = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0;
+        for j in 0..4 {
+            let (limb, next) = mac(wide[i + j], a[i], b[j], carry);
+            wide[i + j] = limb;
+            carry = next;
+        }
+        wide[i + 4] = carry;
+    }
+    [wide[4], wide[5], wide[6], wide[7]]
+}
+
+/// Returns the absolute value and sign of `k`, which must be in the range
+/// $(-2^{128}, 2^{128})$.
+fn short_abs<F: PrimeField<Repr = [u8; 32]>>(k: &F) -> (u128, Choice) {
+    let repr = k.to_repr();
+    let is_neg = !repr[16..].iter().fold(0u8, |acc, b| acc | b).ct_eq(&0);
+    let abs = F::conditional_select(k, &-*k, is_neg).to_repr();
+    (u128::from_le_bytes(abs[..16].try_into().unwrap()), is_neg)
+}
+
+macro_rules! impl_mul_glv {
+    ($name:ident, $base:ident, $scalar:ident) => {
+        impl $name {
+            /// Decomposes `k` into $(k_1, k_2)$ such that
+            /// $k \equiv k_1 + k_2 \zeta$, returning the absolute value and sign
+            /// of each half. Both halves are less than $2^{128}$ in absolute value.
+            fn glv_decompose(k: &$scalar) -> ((u128, Choice), (u128, Choice)) {
+                let repr = k.to_repr();
+                let mut limbs = [0u64; 4];
+                for (limb, bytes) in limbs.iter_mut().zip(repr.chunks_exact(8)) {
+                    *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+
+                // c_1 and c_2 approximate b_2 k / r and -b_1 k / r respectively.
+                let c1 = $scalar::from_raw(mul_shift_256(&limbs, &Self::GLV_G1));
+                let c2 = $scalar::from_raw(mul_shift_256(&limbs, &Self::GLV_G2));
+
+                let k2 = -(c1 * Self::GLV_B1 + c2 * Self::GLV_B2);
+                let k1 = *k - k2 * $scalar::ZETA;
+
+                (short_abs(&k1), short_abs(&k2))
+            }
+
+            /// Multiplies this point by `k` using the GLV method: `k` is split into
+            /// two halves of at most 128 bits, which are applied to this point and
+            /// its image under the curve endomorphism in a joint double-and-add.
+            /// This halves the number of doublings compared to `self * k`.
+            pub fn mul_glv(&self, k: &$scalar) -> $name {
+                let ((k1, k1_neg), (k2, k2_neg)) = Self::glv_decompose(k);
+
+                let p1 = $name::conditional_select(self, &-self, k1_neg);
+                let endo = $name {
+                    x: self.x * $base::ZETA,
+                    y: self.y,
+                    z: self.z,
+                };
+                let p2 = $name::conditional_select(&endo, &-endo, k2_neg);
+
+                let mut acc = $name::identity();
+                for i in (0..128).rev() {
+                    acc = acc.double();
+                    acc = $name::conditional_select(
+                        &acc,
+                        &(acc + p1),
+                        Choice::from(((k1 >> i) & 1) as u8),
+                    );
+                    acc = $name::conditional_select(
+                        &acc,
+                        &(acc + p2),
+                        Choice::from(((k2 >> i) & 1) as u8),
+                    );
+                }
+
+                acc
+            }
+        }
+    };
+}
+
+impl_mul_glv!(Ep, Fp, Fq);
+impl_mul_glv!(Eq, Fq, Fp);
+
+macro_rules! impl_from_xy_unchecked {
+    ($name_affine:ident, $base:ident) => {
+        impl $name_affine {
+            /// Constructs a point from its affine coordinates without checking that
+            /// they satisfy the curve equation.
+            ///
+            /// This is a `const fn`, intended for tables of precomputed points whose
+            /// coordinates are known to be valid. It must not be used with untrusted
+            /// input, because the group law gives meaningless results for points off
+            /// the curve. Use [`CurveAffine::from_xy`](crate::arithmetic::CurveAffine::from_xy)
+            /// to validate coordinates.
+            pub const fn from_xy_unchecked(x: $base, y: $base) -> Self {
+                $name_affine { x, y }
+            }
+        }
+    };
+}
+
+impl_from_xy_unchecked!(EpAffine, Fp);
+impl_from_xy_unchecked!(EqAffine, Fq);
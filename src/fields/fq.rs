@@ -1,9 +1,9 @@
 use core::fmt;
+use core::iter::{Product, Sum};
 use core::ops::{Add, Mul, Neg, Sub};
 
 use ff::PrimeField;
 use rand::RngCore;
-use serde::{Serialize, Deserialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "sqrt-table")]
@@ -25,7 +25,7 @@ use crate::arithmetic::SqrtTables;
 // The internal representation of this type is four 64-bit unsigned
 // integers in little-endian order. `Fq` values are always in
 // Montgomery form; i.e., Fq(a) = aR mod q, with R = 2^256.
-#[derive(Clone, Copy, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Eq)]
 #[repr(transparent)]
 pub struct Fq(pub(crate) [u64; 4]);
 
@@ -174,6 +174,30 @@ impl<'a, 'b> Mul<&'b Fq> for &'a Fq {
 impl_binops_additive!(Fq, Fq);
 impl_binops_multiplicative!(Fq, Fq);
 
+impl<T> Sum<T> for Fq
+where
+    T: core::borrow::Borrow<Fq>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, item| acc + item.borrow())
+    }
+}
+
+impl<T> Product<T> for Fq
+where
+    T: core::borrow::Borrow<Fq>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::one(), |acc, item| acc * item.borrow())
+    }
+}
+
 /// INV = -(q^{-1} mod 2^64) mod 2^64
 const INV: u64 = 0x8c46eb20ffffffff;
 
@@ -248,6 +272,15 @@ impl Default for Fq {
 }
 
 impl Fq {
+    /// Generator of the multiplicative group of the field, $5$. This is equal
+    /// to `PrimeField::multiplicative_generator()`.
+    pub const MULTIPLICATIVE_GENERATOR: Fq = GENERATOR;
+
+    /// Primitive $2^{32}$-th root of unity, $5^t$ where
+    /// $t \cdot 2^{32} + 1 = q$ with $t$ odd. This is equal to
+    /// `PrimeField::root_of_unity()`.
+    pub const ROOT_OF_UNITY: Fq = ROOT_OF_UNITY;
+
     /// Returns zero, the additive identity.
     #[inline]
     pub const fn zero() -> Fq {
@@ -267,6 +300,41 @@ impl Fq {
         self.add(self)
     }
 
+    /// Doubles this field element in place.
+    #[inline]
+    pub fn double_assign(&mut self) {
+        *self = self.double();
+    }
+
+    /// Triples this field element.
+    #[inline]
+    pub const fn triple(&self) -> Fq {
+        (&self.double()).add(self)
+    }
+
+    /// Halves this field element.
+    ///
+    /// This is equivalent to multiplying by [`FieldExt::TWO_INV`], but only needs
+    /// an addition and a shift.
+    #[inline]
+    pub const fn halve(&self) -> Fq {
+        // Halving commutes with the Montgomery factor, so we can halve the
+        // internal representation directly. If it is odd, add the modulus to make
+        // it even first; the sum is less than 2^256, so it cannot overflow.
+        let mask = (self.0[0] & 1).wrapping_neg();
+        let (d0, carry) = adc(self.0[0], MODULUS.0[0] & mask, 0);
+        let (d1, carry) = adc(self.0[1], MODULUS.0[1] & mask, carry);
+        let (d2, carry) = adc(self.0[2], MODULUS.0[2] & mask, carry);
+        let (d3, _) = adc(self.0[3], MODULUS.0[3] & mask, carry);
+
+        Fq([
+            (d0 >> 1) | (d1 << 63),
+            (d1 >> 1) | (d2 << 63),
+            (d2 >> 1) | (d3 << 63),
+            d3 >> 1,
+        ])
+    }
+
     fn from_u512(limbs: [u64; 8]) -> Fq {
         // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
         // with the higher bits multiplied by 2^256. Thus, we perform two reductions
@@ -293,6 +361,51 @@ impl Fq {
         (&Fq(val)).mul(&R2)
     }
 
+    /// Returns the canonical integer value of this element as little-endian
+    /// 64-bit limbs.
+    ///
+    /// This is the inverse of [`Fq::from_raw`] for canonical inputs, and is not the
+    /// internal Montgomery form.
+    pub const fn to_raw(&self) -> [u64; 4] {
+        // (a.R) / R = a
+        Fq::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0).0
+    }
+
+    /// Returns the canonical little-endian encoding of this element.
+    ///
+    /// This is the same as [`PrimeField::to_repr`].
+    pub fn to_bytes_le(&self) -> [u8; 32] {
+        self.to_repr()
+    }
+
+    /// Returns the canonical big-endian encoding of this element.
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = self.to_repr();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Attempts to decode a little-endian encoding, failing if it is not
+    /// canonical.
+    ///
+    /// This is the same as [`PrimeField::from_repr`].
+    pub fn from_bytes_le(bytes: &[u8; 32]) -> CtOption<Fq> {
+        Fq::from_repr(*bytes)
+    }
+
+    /// Attempts to decode a big-endian encoding, failing if it is not canonical.
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> CtOption<Fq> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Fq::from_repr(repr)
+    }
+
+    /// Squares this element in place.
+    #[inline]
+    pub fn square_assign(&mut self) {
+        *self = self.square();
+    }
+
     /// Squares this element.
     #[inline]
     pub const fn square(&self) -> Fq {
@@ -373,9 +486,14 @@ impl Fq {
         (&Fq([r4, r5, r6, r7])).sub(&MODULUS)
     }
 
-    /// Multiplies `rhs` by `self`, returning the result.
+    /// Multiplies the internal Montgomery representations of `self` and `rhs`,
+    /// returning the unreduced 512-bit product as its low and high 256 bits, in
+    /// little-endian 64-bit limbs.
+    ///
+    /// Together with [`Fq::montgomery_reduce_wide`], this allows products to be
+    /// accumulated before a single reduction.
     #[inline]
-    pub const fn mul(&self, rhs: &Self) -> Self {
+    pub const fn mul_wide(&self, rhs: &Self) -> ([u64; 4], [u64; 4]) {
         // Schoolbook multiplication
 
         let (r0, carry) = mac(0, self.0[0], rhs.0[0], 0);
@@ -398,7 +516,28 @@ impl Fq {
         let (r5, carry) = mac(r5, self.0[3], rhs.0[2], carry);
         let (r6, r7) = mac(r6, self.0[3], rhs.0[3], carry);
 
-        Fq::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
+        ([r0, r1, r2, r3], [r4, r5, r6, r7])
+    }
+
+    /// Performs a Montgomery reduction of the 512-bit integer with low and high
+    /// 256 bits `lo` and `hi`, in little-endian 64-bit limbs, returning the field
+    /// element whose Montgomery representation is that integer divided by
+    /// $2^{256}$ modulo the field order.
+    ///
+    /// Reducing the output of [`Fq::mul_wide`] gives the field product. A sum of up
+    /// to three such products can also be reduced at once, because it is less than
+    /// the field order times $2^{256}$. Larger inputs give incorrect or
+    /// non-canonical results.
+    #[inline]
+    pub const fn montgomery_reduce_wide(lo: [u64; 4], hi: [u64; 4]) -> Fq {
+        Fq::montgomery_reduce(lo[0], lo[1], lo[2], lo[3], hi[0], hi[1], hi[2], hi[3])
+    }
+
+    /// Multiplies `rhs` by `self`, returning the result.
+    #[inline]
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        let (lo, hi) = self.mul_wide(rhs);
+        Fq::montgomery_reduce_wide(lo, hi)
     }
 
     /// Subtracts `rhs` from `self`, returning the result.
@@ -482,6 +621,8 @@ impl Group for Fq {
 
 impl ff::Field for Fq {
     fn random(mut rng: impl RngCore) -> Self {
+        // Reducing 512 uniform bits leaves a bias of less than 2^-256, and always
+        // consumes exactly 64 bytes of RNG output, whatever the result.
         Self::from_u512([
             rng.next_u64(),
             rng.next_u64(),
@@ -520,20 +661,56 @@ impl ff::Field for Fq {
         }
 
         #[cfg(not(feature = "sqrt-table"))]
-        crate::arithmetic::sqrt_tonelli_shanks(self, &T_MINUS1_OVER2)
+        crate::arithmetic::sqrt_tonelli_shanks(self)
     }
 
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     fn invert(&self) -> CtOption<Self> {
-        let tmp = self.pow_vartime(&[
-            0x8c46eb20ffffffff,
-            0x224698fc0994a8dd,
-            0x0,
-            0x4000000000000000,
-        ]);
+        // Computes self^(p - 2) with a fixed addition chain: a sliding window of up
+        // to 4 bits over p - 2, using precomputed odd powers. The sequence of
+        // operations depends only on p, so this is constant time.
+        let sqr = |x: Fq, i: u32| (0..i).fold(x, |x, _| x.square());
 
-        CtOption::new(tmp, !self.ct_eq(&Self::zero()))
+        let r10 = self.square();
+        let r11 = r10 * self;
+        let r101 = r11 * r10;
+        let r111 = r101 * r10;
+        let r1001 = r111 * r10;
+        let r1011 = r1001 * r10;
+        let r1101 = r1011 * r10;
+        let r1111 = r1101 * r10;
+
+        let mut res = *self;
+        res = sqr(res, 129) * self;
+        res = sqr(res, 7) * r1001;
+        res = sqr(res, 7) * r1101;
+        res = sqr(res, 4) * r11;
+        res = sqr(res, 7) * r1111;
+        res = sqr(res, 2) * r11;
+        res = sqr(res, 10) * r1001;
+        res = sqr(res, 4) * r1001;
+        res = sqr(res, 5) * r1001;
+        res = sqr(res, 4) * r101;
+        res = sqr(res, 7) * r1101;
+        res = sqr(res, 4) * r1101;
+        res = sqr(res, 1) * self;
+        res = sqr(res, 5) * r11;
+        res = sqr(res, 4) * self;
+        res = sqr(res, 7) * r1101;
+        res = sqr(res, 4) * r1101;
+        res = sqr(res, 3) * r11;
+        res = sqr(res, 3) * self;
+        res = sqr(res, 9) * r1111;
+        res = sqr(res, 4) * r1111;
+        res = sqr(res, 4) * r1111;
+        res = sqr(res, 4) * r1111;
+        res = sqr(res, 4) * r1111;
+        res = sqr(res, 4) * r1111;
+        res = sqr(res, 4) * r1111;
+        res = sqr(res, 4) * r1111;
+
+        CtOption::new(res, !self.ct_eq(&Self::zero()))
     }
 
     fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
@@ -724,13 +901,16 @@ impl SqrtRatio for Fq {
 impl FieldExt for Fq {
     const MODULUS: &'static str =
         "0x40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001";
+    /// $5^{-t}$, where $t \cdot 2^{32} + 1 = q$ with $t$ odd.
     const ROOT_OF_UNITY_INV: Self = Fq::from_raw([
         0x57eecda0a84b6836,
         0x4ad38b9084b8a80c,
         0xf4c8f353124086c1,
         0x2235e1a7415bf936,
     ]);
+    /// $5^{2^{32}}$.
     const DELTA: Self = DELTA;
+    /// $(q + 1) / 2$.
     const TWO_INV: Self = Fq::from_raw([
         0xc623759080000001,
         0x11234c7e04ca546e,
@@ -803,6 +983,194 @@ fn test_inv() {
     assert_eq!(inv, INV);
 }
 
+#[test]
+fn test_invert() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert!(bool::from(Fq::zero().invert().is_none()));
+    assert_eq!(Fq::one().invert().unwrap(), Fq::one());
+    assert_eq!((-Fq::one()).invert().unwrap(), -Fq::one());
+
+    // The addition chain agrees with the generic exponentiation by p - 2.
+    let mut p_minus_2 = MODULUS.0;
+    p_minus_2[0] -= 2;
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        let a_inv = a.invert().unwrap();
+        assert_eq!(a_inv, a.pow_vartime(&p_minus_2));
+        assert_eq!(a * a_inv, Fq::one());
+    }
+}
+
+#[test]
+fn test_mul_wide() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn add_wide(a: ([u64; 4], [u64; 4]), b: ([u64; 4], [u64; 4])) -> ([u64; 4], [u64; 4]) {
+        let mut sum = [0u64; 8];
+        let mut carry = 0;
+        let a = a.0.iter().chain(a.1.iter());
+        let b = b.0.iter().chain(b.1.iter());
+        for ((s, a), b) in sum.iter_mut().zip(a).zip(b) {
+            let (limb, c) = adc(*a, *b, carry);
+            *s = limb;
+            carry = c;
+        }
+        assert_eq!(carry, 0);
+        (
+            [sum[0], sum[1], sum[2], sum[3]],
+            [sum[4], sum[5], sum[6], sum[7]],
+        )
+    }
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        let (a, b) = (Fq::random(&mut rng), Fq::random(&mut rng));
+        let (lo, hi) = a.mul_wide(&b);
+        assert_eq!(Fq::montgomery_reduce_wide(lo, hi), a * b);
+
+        // Up to three products can be accumulated before reducing.
+        let lhs = [a, Fq::random(&mut rng), -Fq::one()];
+        let rhs = [b, Fq::random(&mut rng), -Fq::one()];
+        let (lo, hi) = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(x, y)| x.mul_wide(y))
+            .reduce(add_wide)
+            .unwrap();
+        let expected = lhs.iter().zip(rhs.iter()).map(|(x, y)| x * y).sum();
+        assert_eq!(Fq::montgomery_reduce_wide(lo, hi), expected);
+    }
+}
+
+#[test]
+fn test_invert_or_zero() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    assert_eq!(Fq::zero().invert_or_zero(), Fq::zero());
+    assert_eq!(Fq::one().invert_or_zero(), Fq::one());
+    assert_eq!(Fq::from(2).invert_or_zero(), Fq::TWO_INV);
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        assert_eq!(a.invert_or_zero(), a.invert().unwrap());
+        assert_eq!(a * a.invert_or_zero(), Fq::one());
+    }
+}
+
+#[test]
+fn test_sum_and_product() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let values: std::vec::Vec<Fq> = (0..10).map(|_| Fq::random(&mut rng)).collect();
+    let sum = values.iter().fold(Fq::zero(), |acc, v| acc + v);
+    let product = values.iter().fold(Fq::one(), |acc, v| acc * v);
+
+    // Both owned and borrowed items are accepted.
+    assert_eq!(values.iter().sum::<Fq>(), sum);
+    assert_eq!(values.iter().cloned().sum::<Fq>(), sum);
+    assert_eq!(values.iter().product::<Fq>(), product);
+    assert_eq!(values.iter().cloned().product::<Fq>(), product);
+
+    // Empty iterators give the identities.
+    assert_eq!(core::iter::empty::<Fq>().sum::<Fq>(), Fq::zero());
+    assert_eq!(core::iter::empty::<Fq>().product::<Fq>(), Fq::one());
+}
+
+#[test]
+fn test_square_and_cube() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        assert_eq!(a.square(), a * a);
+        assert_eq!(a.cube(), a * a * a);
+
+        let mut b = a;
+        b.square_assign();
+        assert_eq!(b, a.square());
+    }
+}
+
+#[test]
+fn test_double_and_halve() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(Fq::zero().halve(), Fq::zero());
+    assert_eq!(Fq::one().halve(), Fq::TWO_INV);
+    assert_eq!((-Fq::one()).halve(), -Fq::TWO_INV);
+
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        assert_eq!(a.double(), a + a);
+        assert_eq!(a.triple(), a + a + a);
+        assert_eq!(a.double().halve(), a);
+        assert_eq!(a.halve().double(), a);
+        assert_eq!(a.halve(), a * Fq::TWO_INV);
+
+        let mut b = a;
+        b.double_assign();
+        assert_eq!(b, a.double());
+    }
+}
+
+#[test]
+fn test_neg() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(-Fq::zero(), Fq::zero());
+    assert_eq!(Fq::zero().neg(), Fq::zero());
+    assert_eq!(-Fq::one() + Fq::one(), Fq::zero());
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        assert_eq!(a + (-a), Fq::zero());
+        assert_eq!(a.neg(), -a);
+        assert_eq!(-(-a), a);
+        assert_eq!(Fq::zero() - a, -a);
+    }
+}
+
 #[test]
 fn test_sqrt() {
     // NB: TWO_INV is standing in as a "random" field element
@@ -817,6 +1185,61 @@ fn test_pow_by_t_minus1_over2() {
     assert!(v == ff::Field::pow_vartime(&Fq::TWO_INV, &T_MINUS1_OVER2));
 }
 
+#[test]
+fn test_sqrt_random_squares() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..1000 {
+        let a = Fq::random(&mut rng);
+        let square = a.square();
+
+        // The addition chain agrees with generic exponentiation.
+        assert_eq!(
+            square.pow_by_t_minus1_over2(),
+            square.pow_vartime(&T_MINUS1_OVER2)
+        );
+
+        let root = square.sqrt().unwrap();
+        assert!(root == a || root == -a);
+    }
+}
+
+#[test]
+fn test_legendre() {
+    use crate::arithmetic::LegendreSymbol;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(Fq::zero().legendre(), LegendreSymbol::Zero);
+    assert_eq!(Fq::one().legendre(), LegendreSymbol::QuadraticResidue);
+    assert_eq!(
+        Fq::root_of_unity().legendre(),
+        LegendreSymbol::QuadraticNonResidue
+    );
+
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        let expected = if bool::from(a.sqrt().is_some()) {
+            LegendreSymbol::QuadraticResidue
+        } else {
+            LegendreSymbol::QuadraticNonResidue
+        };
+        assert_eq!(a.legendre(), expected);
+        assert_eq!(a.square().legendre(), LegendreSymbol::QuadraticResidue);
+    }
+}
+
 #[test]
 fn test_sqrt_ratio_and_alt() {
     // (true, sqrt(num/div)), if num and div are nonzero and num/div is a square in the field
@@ -863,6 +1286,24 @@ fn test_sqrt_ratio_and_alt() {
     assert!(v == expected);
 }
 
+#[test]
+fn test_sqrt_alt_known_values() {
+    // Squares, including zero.
+    for (v, root) in [(0u64, 0u64), (1, 1), (4, 2), (9, 3), (49, 7)].iter() {
+        let (is_square, r) = Fq::from(*v).sqrt_alt();
+        assert!(bool::from(is_square));
+        assert!(r == Fq::from(*root) || r == -Fq::from(*root));
+    }
+
+    // The multiplicative generator is a nonsquare, as are its odd powers.
+    for v in [GENERATOR, GENERATOR.cube()].iter() {
+        let (is_square, r) = v.sqrt_alt();
+        assert!(!bool::from(is_square));
+        assert_eq!(r.square(), Fq::root_of_unity() * v);
+        assert!(bool::from(v.sqrt().is_none()));
+    }
+}
+
 #[test]
 fn test_zeta() {
     assert_eq!(
@@ -883,6 +1324,14 @@ fn test_root_of_unity() {
         Fq::root_of_unity().pow_vartime(&[1 << Fq::S, 0, 0, 0]),
         Fq::one()
     );
+    assert_eq!(Fq::ROOT_OF_UNITY, Fq::root_of_unity());
+    assert_eq!(Fq::ROOT_OF_UNITY.pow_vartime(&[1 << Fq::S]), Fq::one());
+    assert_eq!(
+        Fq::ROOT_OF_UNITY.pow_vartime(&[1 << (Fq::S - 1)]),
+        -Fq::one()
+    );
+    assert_eq!(Fq::MULTIPLICATIVE_GENERATOR, Fq::multiplicative_generator());
+    assert_eq!(Fq::MULTIPLICATIVE_GENERATOR, Fq::from(5));
 }
 
 #[test]
@@ -893,6 +1342,105 @@ fn test_inv_root_of_unity() {
 #[test]
 fn test_inv_2() {
     assert_eq!(Fq::TWO_INV, Fq::from(2).invert().unwrap());
+    assert_eq!(Fq::TWO_INV + Fq::TWO_INV, Fq::one());
+}
+
+#[test]
+fn test_pow() {
+    use rand::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        let base = Fq::random(&mut rng);
+        let exp = [
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+        ];
+        assert_eq!(base.pow(&exp), base.pow_vartime(&exp));
+    }
+
+    let base = Fq::random(&mut rng);
+    assert_eq!(base.pow(&[0, 0, 0, 0]), Fq::one());
+    assert_eq!(base.pow(&[1, 0, 0, 0]), base);
+    assert_eq!(Fq::zero().pow(&[0, 0, 0, 0]), Fq::one());
+}
+
+#[test]
+fn test_from_repr_rejects_non_canonical() {
+    let mut modulus = [0u8; 32];
+    for (bytes, limb) in modulus.chunks_mut(8).zip(MODULUS.0.iter()) {
+        bytes.copy_from_slice(&limb.to_le_bytes());
+    }
+
+    // The largest canonical encoding is p - 1.
+    let mut p_minus_one = modulus;
+    p_minus_one[0] -= 1;
+    assert_eq!(Fq::from_repr(p_minus_one).unwrap(), -Fq::one());
+
+    // Encodings of p, p + 1, and 2^256 - 1 are all out of range.
+    let mut p_plus_one = modulus;
+    p_plus_one[0] += 1;
+    assert!(bool::from(Fq::from_repr(modulus).is_none()));
+    assert!(bool::from(Fq::from_repr(p_plus_one).is_none()));
+    assert!(bool::from(Fq::from_repr([0xff; 32]).is_none()));
+}
+
+#[test]
+fn test_conditional_selection() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let a = Fq::random(&mut rng);
+    let b = Fq::random(&mut rng);
+
+    assert_eq!(Fq::conditional_select(&a, &b, Choice::from(0u8)), a);
+    assert_eq!(Fq::conditional_select(&a, &b, Choice::from(1u8)), b);
+
+    let mut c = a;
+    c.conditional_assign(&b, Choice::from(0u8));
+    assert_eq!(c, a);
+    c.conditional_assign(&b, Choice::from(1u8));
+    assert_eq!(c, b);
+
+    for (x, y) in [(a, a), (a, b), (b, a), (Fq::zero(), Fq::zero())].iter() {
+        assert_eq!(bool::from(x.ct_eq(y)), x == y);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_invert() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut v: std::vec::Vec<Fq> = (0..16).map(|_| Fq::random(&mut rng)).collect();
+    v[0] = Fq::zero();
+    v[7] = Fq::zero();
+    v[15] = Fq::zero();
+    let expected: std::vec::Vec<Fq> = v.iter().map(|x| x.invert().unwrap_or(Fq::zero())).collect();
+
+    Fq::batch_invert(&mut v);
+    assert_eq!(v, expected);
+
+    let mut empty: [Fq; 0] = [];
+    Fq::batch_invert(&mut empty);
 }
 
 #[test]
@@ -942,3 +1490,307 @@ fn test_from_u512() {
         ])
     );
 }
+
+#[test]
+fn test_from_integers() {
+    assert_eq!(Fq::from(5) + Fq::from(7), Fq::from(12));
+    assert_eq!(Fq::from(u64::MAX) + Fq::one(), Fq::from_u128(1 << 64));
+    assert_eq!(Fq::from(u64::MAX), Fq::from_raw([u64::MAX, 0, 0, 0]));
+
+    // 2^127 is smaller than the modulus, so it is already reduced.
+    let two_127 = Fq::from_u128(1 << 127);
+    assert_eq!(two_127, Fq::from_raw([0, 1 << 63, 0, 0]));
+    assert_eq!(two_127, Fq::from(2).pow_vartime(&[127]));
+    assert_eq!(two_127.to_raw(), [0, 1 << 63, 0, 0]);
+    assert_eq!(Fq::from_u128(u128::MAX) + Fq::one(), two_127.double());
+}
+
+#[test]
+fn test_to_raw() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    assert_eq!(Fq::zero().to_raw(), [0, 0, 0, 0]);
+    assert_eq!(Fq::one().to_raw(), [1, 0, 0, 0]);
+    let mut p_minus_one = MODULUS.0;
+    p_minus_one[0] -= 1;
+    assert_eq!((-Fq::one()).to_raw(), p_minus_one);
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        assert_eq!(Fq::from_raw(a.to_raw()), a);
+
+        // The limbs agree with the byte encoding.
+        let repr = a.to_repr();
+        for (limb, bytes) in a.to_raw().iter().zip(repr.chunks(8)) {
+            assert_eq!(*limb, u64::from_le_bytes(bytes.try_into().unwrap()));
+        }
+    }
+}
+
+#[test]
+fn test_endianness() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::from_hex;
+
+    // p - 1, big-endian, from the modulus.
+    let mut p_minus_one_be = from_hex(&Fq::MODULUS[2..]);
+    p_minus_one_be[31] -= 1;
+    assert_eq!((-Fq::one()).to_bytes_be(), p_minus_one_be);
+    assert_eq!(Fq::from_bytes_be(&p_minus_one_be).unwrap(), -Fq::one());
+
+    // Fixed encodings of 1 and 2^64 + 1, the latter spanning two limbs.
+    let one_le = from_hex("0100000000000000000000000000000000000000000000000000000000000000");
+    let one_be = from_hex("0000000000000000000000000000000000000000000000000000000000000001");
+    assert_eq!(Fq::one().to_bytes_le(), one_le);
+    assert_eq!(Fq::one().to_bytes_be(), one_be);
+    assert_eq!(Fq::from_bytes_le(&one_le).unwrap(), Fq::one());
+    assert_eq!(Fq::from_bytes_be(&one_be).unwrap(), Fq::one());
+    let a = Fq::from_raw([1, 1, 0, 0]);
+    let a_le = from_hex("0100000000000000010000000000000000000000000000000000000000000000");
+    let a_be = from_hex("0000000000000000000000000000000000000000000000010000000000000001");
+    assert_eq!(a.to_bytes_le(), a_le);
+    assert_eq!(a.to_bytes_be(), a_be);
+    assert_eq!(Fq::from_bytes_le(&a_le).unwrap(), a);
+    assert_eq!(Fq::from_bytes_be(&a_be).unwrap(), a);
+
+    // The modulus itself is not canonical in either order.
+    let modulus_be = from_hex(&Fq::MODULUS[2..]);
+    let mut modulus_le = modulus_be;
+    modulus_le.reverse();
+    assert!(bool::from(Fq::from_bytes_be(&modulus_be).is_none()));
+    assert!(bool::from(Fq::from_bytes_le(&modulus_le).is_none()));
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        assert_eq!(a.to_bytes_le(), a.to_repr());
+        assert_eq!(Fq::from_bytes_le(&a.to_bytes_le()).unwrap(), a);
+        assert_eq!(Fq::from_bytes_be(&a.to_bytes_be()).unwrap(), a);
+
+        let mut reversed = a.to_bytes_le();
+        reversed.reverse();
+        assert_eq!(a.to_bytes_be(), reversed);
+    }
+}
+
+#[test]
+fn test_from_str_radix() {
+    use crate::arithmetic::ParseError;
+
+    assert_eq!(Fq::from_str_radix("0", 10).unwrap(), Fq::zero());
+    assert_eq!(Fq::from_str_radix("1_000", 10).unwrap(), Fq::from(1000));
+    assert_eq!(Fq::from_str_radix("0xff", 16).unwrap(), Fq::from(255));
+    assert_eq!(
+        Fq::from_str_radix("DEAD_beef", 16).unwrap(),
+        Fq::from(0xdead_beef)
+    );
+
+    // The modulus minus one, in both bases.
+    let minus_one = -Fq::one();
+    assert_eq!(
+        Fq::from_str_radix(
+            "28948022309329048855892746252171976963363056481941647379679742748393362948096",
+            10
+        )
+        .unwrap(),
+        minus_one
+    );
+    let mut hex = std::string::String::from(Fq::MODULUS);
+    hex.pop();
+    hex.push('0');
+    assert_eq!(Fq::from_str_radix(&hex, 16).unwrap(), minus_one);
+    assert_eq!(Fq::from_str_radix(&hex[2..], 16).unwrap(), minus_one);
+
+    // Out-of-range values are reduced.
+    assert_eq!(Fq::from_str_radix(Fq::MODULUS, 16).unwrap(), Fq::zero());
+    assert_eq!(
+        Fq::from_str_radix(
+            "28948022309329048855892746252171976963363056481941647379679742748393362948102",
+            10
+        )
+        .unwrap(),
+        Fq::from(5)
+    );
+
+    assert_eq!(
+        Fq::from_str_radix("12", 8),
+        Err(ParseError::UnsupportedRadix(8))
+    );
+    assert_eq!(Fq::from_str_radix("", 10), Err(ParseError::Empty));
+    assert_eq!(Fq::from_str_radix("0x", 16), Err(ParseError::Empty));
+    assert_eq!(Fq::from_str_radix("_", 10), Err(ParseError::Empty));
+    assert_eq!(
+        Fq::from_str_radix("12a", 10),
+        Err(ParseError::InvalidDigit('a'))
+    );
+    assert_eq!(
+        Fq::from_str_radix("0x12", 10),
+        Err(ParseError::InvalidDigit('x'))
+    );
+    assert_eq!(
+        Fq::from_str_radix("-1", 10),
+        Err(ParseError::InvalidDigit('-'))
+    );
+}
+
+#[test]
+fn test_parity() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    assert!(bool::from(Fq::zero().is_even()));
+    assert!(bool::from(Fq::one().is_odd()));
+    assert!(bool::from(Fq::from(2).is_even()));
+    assert!(bool::from(Fq::from(3).is_odd()));
+    // p - 1 is even, since p is odd.
+    assert!(bool::from((-Fq::one()).is_even()));
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        // Parity is that of the canonical value, not the Montgomery form.
+        assert_eq!(bool::from(a.is_odd()), a.to_raw()[0] & 1 == 1);
+        assert_eq!(bool::from(a.is_even()), !bool::from(a.is_odd()));
+        assert_eq!(
+            bool::from(Fq::from_repr(a.to_repr()).unwrap().is_odd()),
+            bool::from(a.is_odd())
+        );
+        // Negation flips the parity of nonzero elements.
+        assert_eq!(bool::from((-a).is_odd()), bool::from(a.is_even()));
+    }
+}
+
+#[test]
+fn test_random_is_deterministic() {
+    use rand::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    let seed = [
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ];
+
+    // The same seed produces the same sequence of field elements.
+    let mut rng_a = XorShiftRng::from_seed(seed);
+    let mut rng_b = XorShiftRng::from_seed(seed);
+    for _ in 0..100 {
+        assert_eq!(Fq::random(&mut rng_a), Fq::random(&mut rng_b));
+    }
+
+    // Elements are sampled by reducing 512 bits of RNG output.
+    let mut rng_a = XorShiftRng::from_seed(seed);
+    let mut rng_b = XorShiftRng::from_seed(seed);
+    let mut limbs = [0u64; 8];
+    for limb in limbs.iter_mut() {
+        *limb = rng_b.next_u64();
+    }
+    assert_eq!(Fq::random(&mut rng_a), Fq::from_u512(limbs));
+
+    // A different seed produces a different element.
+    let mut other_seed = seed;
+    other_seed[0] ^= 1;
+    assert_ne!(
+        Fq::random(XorShiftRng::from_seed(seed)),
+        Fq::random(XorShiftRng::from_seed(other_seed))
+    );
+}
+
+#[test]
+fn test_random_low_bits_are_uniform() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // Each of the low 8 bits should be set in about half of the samples. With
+    // 2000 samples the standard deviation is about 22, so allow a wide margin.
+    let mut counts = [0u32; 8];
+    for _ in 0..2000 {
+        let low = Fq::random(&mut rng).get_lower_32();
+        for (i, count) in counts.iter_mut().enumerate() {
+            *count += (low >> i) & 1;
+        }
+    }
+    for count in counts.iter() {
+        assert!((850..1150).contains(count), "{:?}", counts);
+    }
+}
+
+#[test]
+fn test_from_bytes_wide() {
+    let mut bytes = [0u8; 64];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    assert_eq!(
+        format!("{:?}", Fq::from_bytes_wide(&bytes)),
+        "0x0eeb900e506a775d4f4d3a3f71d16e3c364e672e55d33bab241dfe028c6f5239"
+    );
+
+    // 2^512 - 1
+    assert_eq!(
+        format!("{:?}", Fq::from_bytes_wide(&[0xff; 64])),
+        "0x096d41af7ccfdaa97fae231004ccf59067bb433d891a16e3fc9678ff0000000e"
+    );
+
+    // Values below the modulus are unchanged.
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&(-Fq::one()).to_repr());
+    assert_eq!(Fq::from_bytes_wide(&bytes), -Fq::one());
+}
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_le_bits() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let five = Fq::from(5).to_le_bits();
+    assert!(five[0] && !five[1] && five[2]);
+    assert!((3..256).all(|i| !five[i]));
+    assert_eq!(Fq::from_le_bits(&[true, false, true]).unwrap(), Fq::from(5));
+    assert_eq!(Fq::from_le_bits(&[]).unwrap(), Fq::zero());
+
+    for _ in 0..100 {
+        let a = Fq::random(&mut rng);
+        let bits = a.to_le_bits();
+        // Bits beyond the modulus length are zero.
+        assert!((Fq::NUM_BITS as usize..256).all(|i| !bits[i]));
+
+        let mut bits: std::vec::Vec<bool> = (0..256).map(|i| bits[i]).collect();
+        assert_eq!(Fq::from_le_bits(&bits).unwrap(), a);
+
+        // Trailing zeros are accepted, but set bits beyond 256 are not.
+        bits.push(false);
+        assert_eq!(Fq::from_le_bits(&bits).unwrap(), a);
+        bits.push(true);
+        assert!(bool::from(Fq::from_le_bits(&bits).is_none()));
+    }
+
+    // The modulus itself is rejected.
+    let modulus = Fq::char_le_bits();
+    let modulus: std::vec::Vec<bool> = (0..256).map(|i| modulus[i]).collect();
+    assert!(bool::from(Fq::from_le_bits(&modulus).is_none()));
+}
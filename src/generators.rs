@@ -0,0 +1,83 @@
+//! Deterministic derivation of independent generators.
+//!
+//! # Example
+//!
+//! ```
+//! use pasta_curves::{generators::generators, pallas};
+//!
+//! let gens: Vec<pallas::Affine> = generators("z.cash:example_generators", 4);
+//! assert_eq!(gens.len(), 4);
+//! ```
+
+use alloc::vec::Vec;
+
+use group::Curve;
+
+use crate::arithmetic::{CurveAffine, CurveExt};
+
+/// Derives `n` generators of the curve `C` from the domain separator
+/// `domain_sep`.
+///
+/// The $i$-th generator is [`CurveExt::hash_to_curve`] applied to the 32-bit
+/// little-endian encoding of $i$. Since they are outputs of a random oracle,
+/// nobody knows a discrete logarithm relation between the generators, and
+/// anyone can recompute them from `domain_sep`. The $i$-th generator does not
+/// depend on `n`.
+///
+/// # Panics
+///
+/// Panics if `n` does not fit in a `u32`.
+pub fn generators<C: CurveAffine>(domain_sep: &str, n: usize) -> Vec<C> {
+    let hasher = C::CurveExt::hash_to_curve(domain_sep);
+
+    let n_u32 = u32::try_from(n).expect("too many generators");
+    let projective: Vec<_> = (0..n_u32).map(|i| hasher(&i.to_le_bytes())).collect();
+    let mut affine = alloc::vec![C::identity(); n];
+    C::CurveExt::batch_normalize(&projective, &mut affine);
+    affine
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use group::GroupEncoding;
+
+    use super::generators;
+    use crate::{from_hex, pallas, vesta};
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        let gens: Vec<pallas::Affine> = generators("pasta_curves:test_generators", 8);
+        assert_eq!(gens, generators("pasta_curves:test_generators", 8));
+        assert_eq!(gens[..3], generators("pasta_curves:test_generators", 3));
+        assert!(gens != generators("pasta_curves:test_generators2", 8));
+        for (i, g) in gens.iter().enumerate() {
+            assert!(gens[i + 1..].iter().all(|other| other != g));
+        }
+    }
+
+    #[test]
+    fn test_generators_pinned() {
+        // These must not change between crate versions.
+        let gens: Vec<pallas::Affine> = generators("pasta_curves:test_generators", 3);
+        let expected = [
+            "7e2be759a04955dda50469cc9f5a577974153f0442a57da820aa35eb8c9f3934",
+            "fe8cbf3b6ed65b080f2da55c0a276d0fc1a9e98b66a878a26740ecdf3d71030f",
+            "74f7997a5c2d5dd3fcc5b10b31e08e971cc7962612a9dbb6c30f61259ef41a03",
+        ];
+        for (g, expected) in gens.iter().zip(expected.iter()) {
+            assert_eq!(g.to_bytes(), from_hex(expected));
+        }
+
+        let gens: Vec<vesta::Affine> = generators("pasta_curves:test_generators", 3);
+        let expected = [
+            "c7a72c886300b073eb0dc302bf40b05065cc8ee0bb063109e4761335823ddb96",
+            "61f09423ca2c40e9fbf11dcd3b421f2847b8ace0aaf914b6c437c2b1af461412",
+            "54d0c29ee29bfb75c2699936beb602d82918daf98171e952a1fa8466493ff990",
+        ];
+        for (g, expected) in gens.iter().zip(expected.iter()) {
+            assert_eq!(g.to_bytes(), from_hex(expected));
+        }
+    }
+}
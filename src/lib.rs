@@ -28,19 +28,265 @@ pub mod vesta;
 #[cfg(feature = "alloc")]
 mod hashtocurve;
 
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod generators;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod pedersen;
+
 pub use curves::*;
 pub use fields::*;
 
 pub extern crate group;
 
+/// Decodes a 64-character hex string into 32 bytes, for test vectors.
+#[cfg(test)]
+pub(crate) fn from_hex(s: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn test_endo_consistency() {
-    use crate::arithmetic::{CurveExt, FieldExt};
-    use group::Group;
+    use crate::arithmetic::{CurveAffine, CurveExt, FieldExt};
+    use group::{prime::PrimeCurveAffine, Curve, Group};
 
     let a = pallas::Point::generator();
     assert_eq!(a * pallas::Scalar::ZETA, a.endo());
+    assert_eq!(a.to_affine().endo(), a.endo().to_affine());
     let a = vesta::Point::generator();
     assert_eq!(a * vesta::Scalar::ZETA, a.endo());
+    assert_eq!(a.to_affine().endo(), a.endo().to_affine());
+
+    // Applying the endomorphism twice multiplies the x-coordinate by ZETA^2, which
+    // corresponds to multiplying the point by ZETA^2 in the scalar field.
+    let a = pallas::Affine::generator();
+    let x = *a.coordinates().unwrap().x();
+    let a2 = a.endo().endo();
+    assert_eq!(
+        *a2.coordinates().unwrap().x(),
+        x * pallas::Base::ZETA.square()
+    );
+    assert_eq!(
+        *a2.coordinates().unwrap().y(),
+        *a.coordinates().unwrap().y()
+    );
+    assert_eq!(a2.to_curve(), a * pallas::Scalar::ZETA.square());
+    assert_eq!(a.endo().endo().endo(), a);
+    let a = vesta::Affine::generator();
+    let x = *a.coordinates().unwrap().x();
+    let a2 = a.endo().endo();
+    assert_eq!(
+        *a2.coordinates().unwrap().x(),
+        x * vesta::Base::ZETA.square()
+    );
+    assert_eq!(
+        *a2.coordinates().unwrap().y(),
+        *a.coordinates().unwrap().y()
+    );
+    assert_eq!(a2.to_curve(), a * vesta::Scalar::ZETA.square());
+    assert_eq!(a.endo().endo().endo(), a);
+}
+
+#[test]
+fn test_standard_traits() {
+    use ff::PrimeField;
+    use group::{
+        cofactor::CofactorCurve,
+        prime::{PrimeCurve, PrimeCurveAffine},
+    };
+
+    // These functions only rely on the `ff` and `group` traits, as a downstream
+    // crate that is generic over its field or curve would.
+    fn field_roundtrip<F: PrimeField>() {
+        let x = F::from(5) * F::from(7) - F::one();
+        assert_eq!(x, F::from(34));
+        assert_eq!(F::from_repr(x.to_repr()).unwrap(), x);
+        assert_eq!(x.square().sqrt().unwrap().square(), x.square());
+        assert_eq!(x * x.invert().unwrap(), F::one());
+        assert_eq!(F::from_str_vartime("34"), Some(x));
+    }
+
+    fn curve_roundtrip<G: PrimeCurve>() {
+        let g = G::generator();
+        let p = g * G::Scalar::from(3);
+        assert_eq!(p, g + g + g);
+        assert_eq!(G::from_bytes(&p.to_bytes()).unwrap(), p);
+        assert_eq!(p.to_affine().to_curve(), p);
+        assert!(bool::from((p - g.double() - g).is_identity()));
+    }
+
+    fn is_cofactor_curve<G: CofactorCurve>() {}
+
+    field_roundtrip::<Fp>();
+    field_roundtrip::<Fq>();
+    curve_roundtrip::<Ep>();
+    curve_roundtrip::<Eq>();
+    is_cofactor_curve::<Ep>();
+    is_cofactor_curve::<Eq>();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_base_scalar_conversions() {
+    use crate::arithmetic::{base_from_scalar, scalar_from_base};
+    use ff::PrimeField;
+
+    // The largest element of Fp, and p itself, as elements of Fq.
+    let p_minus_one = Fq::from_repr((-Fp::one()).to_repr()).unwrap();
+    let p = p_minus_one + Fq::one();
+
+    // Pallas: Fq -> Fp fails at and above p, Fp -> Fq always succeeds.
+    assert_eq!(
+        base_from_scalar::<pallas::Affine>(&p_minus_one).unwrap(),
+        -Fp::one()
+    );
+    assert!(bool::from(base_from_scalar::<pallas::Affine>(&p).is_none()));
+    assert!(bool::from(
+        base_from_scalar::<pallas::Affine>(&-Fq::one()).is_none()
+    ));
+    assert_eq!(
+        scalar_from_base::<pallas::Affine>(&-Fp::one()).unwrap(),
+        p_minus_one
+    );
+
+    // Vesta: Fq -> Fp fails at and above p, Fp -> Fq always succeeds.
+    assert_eq!(
+        scalar_from_base::<vesta::Affine>(&p_minus_one).unwrap(),
+        -Fp::one()
+    );
+    assert!(bool::from(scalar_from_base::<vesta::Affine>(&p).is_none()));
+    assert_eq!(
+        base_from_scalar::<vesta::Affine>(&-Fp::one()).unwrap(),
+        p_minus_one
+    );
+
+    // Values below both moduli are preserved.
+    let x = Fp::from(0x1234_5678);
+    assert_eq!(
+        base_from_scalar::<vesta::Affine>(&x).unwrap(),
+        Fq::from(0x1234_5678)
+    );
+    assert_eq!(
+        scalar_from_base::<vesta::Affine>(&Fq::from(0x1234_5678)).unwrap(),
+        x
+    );
+}
+
+#[test]
+fn test_operator_forms() {
+    use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand::SeedableRng;
+
+    // These only need to compile: each operator is implemented for every
+    // combination of owned and borrowed operands.
+    fn additive<L, R, O>()
+    where
+        L: Add<R, Output = O> + for<'r> Add<&'r R, Output = O>,
+        L: Sub<R, Output = O> + for<'r> Sub<&'r R, Output = O>,
+        for<'l> &'l L: Add<R, Output = O> + Sub<R, Output = O>,
+        for<'l, 'r> &'l L: Add<&'r R, Output = O> + Sub<&'r R, Output = O>,
+    {
+    }
+    fn multiplicative<L, R, O>()
+    where
+        L: Mul<R, Output = O> + for<'r> Mul<&'r R, Output = O>,
+        for<'l> &'l L: Mul<R, Output = O>,
+        for<'l, 'r> &'l L: Mul<&'r R, Output = O>,
+    {
+    }
+    fn additive_assign<L, R>()
+    where
+        L: AddAssign<R> + for<'r> AddAssign<&'r R>,
+        L: SubAssign<R> + for<'r> SubAssign<&'r R>,
+    {
+    }
+    fn multiplicative_assign<L, R>()
+    where
+        L: MulAssign<R> + for<'r> MulAssign<&'r R>,
+    {
+    }
+    fn negation<T>()
+    where
+        T: Neg<Output = T>,
+        for<'a> &'a T: Neg<Output = T>,
+    {
+    }
+
+    additive::<Fp, Fp, Fp>();
+    multiplicative::<Fp, Fp, Fp>();
+    additive_assign::<Fp, Fp>();
+    multiplicative_assign::<Fp, Fp>();
+    negation::<Fp>();
+    additive::<Fq, Fq, Fq>();
+    multiplicative::<Fq, Fq, Fq>();
+    additive_assign::<Fq, Fq>();
+    multiplicative_assign::<Fq, Fq>();
+    negation::<Fq>();
+
+    additive::<Ep, Ep, Ep>();
+    additive::<Ep, EpAffine, Ep>();
+    additive::<EpAffine, Ep, Ep>();
+    additive::<EpAffine, EpAffine, Ep>();
+    multiplicative::<Ep, Fq, Ep>();
+    multiplicative::<EpAffine, Fq, Ep>();
+    additive_assign::<Ep, Ep>();
+    additive_assign::<Ep, EpAffine>();
+    multiplicative_assign::<Ep, Fq>();
+    negation::<Ep>();
+    negation::<EpAffine>();
+    additive::<Eq, Eq, Eq>();
+    additive::<Eq, EqAffine, Eq>();
+    additive::<EqAffine, Eq, Eq>();
+    additive::<EqAffine, EqAffine, Eq>();
+    multiplicative::<Eq, Fp, Eq>();
+    multiplicative::<EqAffine, Fp, Eq>();
+    additive_assign::<Eq, Eq>();
+    additive_assign::<Eq, EqAffine>();
+    multiplicative_assign::<Eq, Fp>();
+    negation::<Eq>();
+    negation::<EqAffine>();
+
+    // All forms agree with each other.
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let a = Fp::random(&mut rng);
+    let b = Fp::random(&mut rng);
+    let expected = (a + b) * a - b;
+    assert_eq!((&a + &b) * &a - &b, expected);
+    assert_eq!((a + &b) * a - &b, expected);
+    assert_eq!((&a + b) * &a - b, expected);
+    let mut acc = a;
+    acc += &b;
+    acc *= &a;
+    acc -= &b;
+    assert_eq!(acc, expected);
+    assert_eq!(-&a, -a);
+
+    let p = Ep::random(&mut rng);
+    let q = Ep::random(&mut rng).to_affine();
+    let scalar = Fq::random(&mut rng);
+    let expected = (p + q) * scalar - q;
+    assert_eq!((&p + &q) * &scalar - &q, expected);
+    assert_eq!((&q + &p) * scalar - q, expected);
+    let mut acc = p;
+    acc += &q;
+    acc *= &scalar;
+    acc -= &q;
+    assert_eq!(acc, expected);
+    assert_eq!(&q * &scalar + &q * scalar, q * (scalar + scalar));
 }
@@ -0,0 +1,151 @@
+//! Pedersen vector commitments over the Pasta curves.
+//!
+//! # Example
+//!
+//! ```
+//! use pasta_curves::{pallas, pedersen::Generators};
+//!
+//! let gens = Generators::<pallas::Affine>::new("z.cash:example_pedersen", 2);
+//! let values = [pallas::Scalar::from(3), pallas::Scalar::from(4)];
+//! let blind = pallas::Scalar::from(5);
+//!
+//! let commitment = gens.commit(&values, blind);
+//! assert!(gens.open(&commitment, &values, blind));
+//! ```
+
+use alloc::vec::Vec;
+
+use group::Curve;
+use subtle::ConstantTimeEq;
+
+use crate::arithmetic::{best_multiexp, CurveAffine, CurveExt};
+use crate::generators::generators;
+
+/// A fixed set of generators for committing to vectors of scalars.
+///
+/// The value generators are derived deterministically from a domain prefix using
+/// [`generators`], and the blinding generator using [`CurveExt::hash_to_curve`],
+/// so nobody knows the discrete logarithm of any generator with respect to the
+/// others, and commitments are reproducible given the same prefix.
+#[derive(Clone, Debug)]
+pub struct Generators<C: CurveAffine> {
+    g: Vec<C>,
+    h: C,
+}
+
+impl<C: CurveAffine> Generators<C> {
+    /// Derives `n` value generators and one blinding generator from
+    /// `domain_prefix`.
+    pub fn new(domain_prefix: &str, n: usize) -> Self {
+        // The value generators are hashed from 4-byte indices, and the blinding
+        // generator from "h", so that the two sets of messages are disjoint.
+        let g = generators(domain_prefix, n);
+        let h = C::CurveExt::hash_to_curve(domain_prefix)(b"h").to_affine();
+
+        Generators { g, h }
+    }
+
+    /// Returns the value generators.
+    pub fn g(&self) -> &[C] {
+        &self.g
+    }
+
+    /// Returns the blinding generator.
+    pub fn h(&self) -> &C {
+        &self.h
+    }
+
+    /// Commits to `values` with the blinding factor `blind`, returning
+    /// $\sum_i v_i \cdot G_i + r \cdot H$.
+    ///
+    /// The value terms are computed with [`best_multiexp`]. The blinding term is
+    /// computed separately with constant-time scalar multiplication, so the
+    /// running time does not depend on `blind`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more values than generators.
+    pub fn commit(&self, values: &[C::Scalar], blind: C::Scalar) -> C::Curve {
+        assert!(values.len() <= self.g.len());
+
+        best_multiexp(values, &self.g[..values.len()]) + self.h * blind
+    }
+
+    /// Returns `true` if `commitment` opens to `values` with the blinding factor
+    /// `blind`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more values than generators.
+    pub fn open(&self, commitment: &C::Curve, values: &[C::Scalar], blind: C::Scalar) -> bool {
+        bool::from(commitment.ct_eq(&self.commit(values, blind)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use group::Group;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::Generators;
+    use crate::arithmetic::CurveAffine;
+    use crate::{pallas, vesta};
+
+    fn test_pedersen<C: CurveAffine>() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let gens = Generators::<C>::new("pasta_curves:test_pedersen", 8);
+
+        // Generators are deterministic, and distinct from each other.
+        let again = Generators::<C>::new("pasta_curves:test_pedersen", 8);
+        assert_eq!(gens.g(), again.g());
+        assert_eq!(gens.h(), again.h());
+        for (i, g) in gens.g().iter().enumerate() {
+            assert!(g != gens.h());
+            assert!(gens.g()[i + 1..].iter().all(|other| other != g));
+        }
+
+        let a: [C::Scalar; 8] = [(); 8].map(|_| C::Scalar::random(&mut rng));
+        let b: [C::Scalar; 8] = [(); 8].map(|_| C::Scalar::random(&mut rng));
+        let r_a = C::Scalar::random(&mut rng);
+        let r_b = C::Scalar::random(&mut rng);
+
+        let c_a = gens.commit(&a, r_a);
+        let c_b = gens.commit(&b, r_b);
+        assert!(gens.open(&c_a, &a, r_a));
+        assert!(!gens.open(&c_a, &b, r_a));
+        assert!(!gens.open(&c_a, &a, r_b));
+
+        // Commitments are additively homomorphic.
+        let mut sum = a;
+        for (s, b) in sum.iter_mut().zip(b.iter()) {
+            *s += b;
+        }
+        assert_eq!(c_a + c_b, gens.commit(&sum, r_a + r_b));
+
+        // Committing to fewer values uses a prefix of the generators.
+        assert_eq!(
+            gens.commit(&a[..3], r_a),
+            gens.commit(
+                &[a[0], a[1], a[2], C::Scalar::zero(), C::Scalar::zero()],
+                r_a
+            )
+        );
+        assert_eq!(gens.commit(&[], C::Scalar::zero()), C::CurveExt::identity());
+    }
+
+    #[test]
+    fn test_pedersen_pallas() {
+        test_pedersen::<pallas::Affine>();
+    }
+
+    #[test]
+    fn test_pedersen_vesta() {
+        test_pedersen::<vesta::Affine>();
+    }
+}
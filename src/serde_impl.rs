@@ -0,0 +1,209 @@
+//! Implementations of `serde::Serialize` and `serde::Deserialize` for the
+//! field and curve types.
+//!
+//! Field elements are encoded using their canonical little-endian
+//! representation, and points using their compressed encoding. Both are
+//! written as hex strings for human-readable formats (such as JSON), and as
+//! 32-byte arrays otherwise.
+
+use ff::PrimeField;
+use group::GroupEncoding;
+use serde_crate::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    curves::{Ep, EpAffine, Eq, EqAffine},
+    fields::{Fp, Fq},
+};
+
+/// Serializes bytes to human readable or compact representation.
+fn serialize_bytes<S: Serializer>(bytes: [u8; 32], s: S) -> Result<S::Ok, S::Error> {
+    if s.is_human_readable() {
+        hex::serde::serialize(bytes, s)
+    } else {
+        bytes.serialize(s)
+    }
+}
+
+/// Deserializes bytes from human readable or compact representation.
+fn deserialize_bytes<'de, D: Deserializer<'de>>(d: D) -> Result<[u8; 32], D::Error> {
+    if d.is_human_readable() {
+        hex::serde::deserialize(d)
+    } else {
+        <[u8; 32]>::deserialize(d)
+    }
+}
+
+impl Serialize for Fp {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.to_repr(), s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Fp {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(d)?;
+        match Fp::from_repr(bytes).into() {
+            Some(fp) => Ok(fp),
+            None => Err(D::Error::custom(
+                "deserialized bytes don't encode a Pallas base field element",
+            )),
+        }
+    }
+}
+
+impl Serialize for Fq {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.to_repr(), s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Fq {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(d)?;
+        match Fq::from_repr(bytes).into() {
+            Some(fq) => Ok(fq),
+            None => Err(D::Error::custom(
+                "deserialized bytes don't encode a Vesta base field element",
+            )),
+        }
+    }
+}
+
+macro_rules! impl_point_serde {
+    ($name:ident, $err:literal) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                serialize_bytes(self.to_bytes(), s)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let bytes = deserialize_bytes(d)?;
+                match $name::from_bytes(&bytes).into() {
+                    Some(p) => Ok(p),
+                    None => Err(D::Error::custom($err)),
+                }
+            }
+        }
+    };
+}
+
+impl_point_serde!(Ep, "deserialized bytes don't encode a Pallas point");
+impl_point_serde!(EpAffine, "deserialized bytes don't encode a Pallas point");
+impl_point_serde!(Eq, "deserialized bytes don't encode a Vesta point");
+impl_point_serde!(EqAffine, "deserialized bytes don't encode a Vesta point");
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+    use std::string::String;
+    use std::vec::Vec;
+
+    use ff::Field;
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::*;
+
+    fn test_roundtrip<T: Serialize + for<'a> Deserialize<'a> + Debug + PartialEq>(t: &T) {
+        let ser = serde_json::to_string(t).unwrap();
+        assert_eq!(*t, serde_json::from_str(&ser).unwrap());
+
+        let ser = bincode::serialize(t).unwrap();
+        assert_eq!(*t, bincode::deserialize(&ser).unwrap());
+    }
+
+    fn rng() -> XorShiftRng {
+        XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ])
+    }
+
+    #[test]
+    fn serde_fields() {
+        let mut rng = rng();
+
+        for f in [Fp::zero(), Fp::one(), -Fp::one()].iter() {
+            test_roundtrip(f);
+        }
+        for f in [Fq::zero(), Fq::one(), -Fq::one()].iter() {
+            test_roundtrip(f);
+        }
+
+        let fps: Vec<Fp> = (0..10).map(|_| Fp::random(&mut rng)).collect();
+        test_roundtrip(&fps);
+        let fqs: Vec<Fq> = (0..10).map(|_| Fq::random(&mut rng)).collect();
+        test_roundtrip(&fqs);
+    }
+
+    #[test]
+    fn serde_points() {
+        let mut rng = rng();
+
+        for _ in 0..10 {
+            let ep = Ep::random(&mut rng);
+            test_roundtrip(&ep);
+            test_roundtrip(&ep.to_affine());
+
+            let eq = Eq::random(&mut rng);
+            test_roundtrip(&eq);
+            test_roundtrip(&eq.to_affine());
+        }
+
+        test_roundtrip(&Ep::identity());
+        test_roundtrip(&EpAffine::identity());
+        test_roundtrip(&Eq::identity());
+        test_roundtrip(&EqAffine::identity());
+    }
+
+    #[test]
+    fn serde_encodings() {
+        let one = "0100000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(
+            serde_json::to_string(&Fp::one()).unwrap(),
+            std::format!("\"{}\"", one)
+        );
+        assert_eq!(
+            serde_json::to_string(&Fq::one()).unwrap(),
+            std::format!("\"{}\"", one)
+        );
+
+        // The compact encoding is the 32 raw bytes, without a length prefix.
+        let mut expected = [0u8; 32];
+        expected[0] = 1;
+        assert_eq!(bincode::serialize(&Fp::one()).unwrap(), expected);
+        assert_eq!(
+            bincode::serialize(&EpAffine::generator()).unwrap(),
+            (-Fp::one()).to_repr()
+        );
+        assert_eq!(
+            serde_json::to_string(&Ep::identity()).unwrap(),
+            std::format!("\"{}\"", "00".repeat(32))
+        );
+    }
+
+    #[test]
+    fn serde_rejects_invalid() {
+        // The moduli are not canonical field element encodings.
+        let p = "01000000ed302d991bf94c09fc98462200000000000000000000000000000040";
+        let q = "0100000021eb468cdda89409fc98462200000000000000000000000000000040";
+        assert!(serde_json::from_str::<Fp>(&std::format!("\"{}\"", p)).is_err());
+        assert!(serde_json::from_str::<Fq>(&std::format!("\"{}\"", q)).is_err());
+        assert!(bincode::deserialize::<Fp>(&[0xff; 32]).is_err());
+        assert!(bincode::deserialize::<Fq>(&[0xff; 32]).is_err());
+
+        // x = 2 is not the x-coordinate of a point on either curve.
+        let x: String = std::format!("\"02{}\"", "00".repeat(31));
+        assert!(serde_json::from_str::<Ep>(&x).is_err());
+        assert!(serde_json::from_str::<EpAffine>(&x).is_err());
+        assert!(serde_json::from_str::<Eq>(&x).is_err());
+        assert!(serde_json::from_str::<EqAffine>(&x).is_err());
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(bincode::deserialize::<EpAffine>(&bytes).is_err());
+        assert!(bincode::deserialize::<EqAffine>(&bytes).is_err());
+    }
+}
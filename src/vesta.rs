@@ -1,4 +1,9 @@
 //! The Vesta and iso-Vesta elliptic curve groups.
+//!
+//! The Vesta curve has prime order $p$, the modulus of [`Fp`], so its cofactor is 1.
+//! Every point on the curve is in the prime-order group, and
+//! [`CofactorGroup::clear_cofactor`](group::cofactor::CofactorGroup::clear_cofactor)
+//! is the identity map.
 
 use super::{Eq, EqAffine, Fp, Fq};
 
@@ -70,3 +75,369 @@ fn test_hash_to_curve() {
         format!("{:?}", z) == "0x1b58d4aa4d68c3f4d9916b77c79ff9911597a27f2ee46244e98eb9615172d2ad"
     );
 }
+
+#[test]
+fn test_point_encoding() {
+    use ff::PrimeField;
+    use group::{prime::PrimeCurveAffine, Curve, Group, GroupEncoding};
+    use rand::SeedableRng;
+
+    use crate::from_hex;
+
+    // The identity is encoded as all zeroes.
+    assert_eq!(Affine::identity().to_bytes(), [0; 32]);
+    assert_eq!(Point::identity().to_bytes(), [0; 32]);
+    assert!(bool::from(
+        Affine::from_bytes(&[0; 32]).unwrap().is_identity()
+    ));
+
+    // The generator is (-1, 2), so its encoding is that of -1 with the sign bit clear.
+    let g = Affine::generator();
+    assert_eq!(g.to_bytes(), (-Base::one()).to_repr());
+
+    // [2] G has an even y-coordinate, and -[2] G an odd one.
+    let g2 = Point::generator().double();
+    let g2_bytes = from_hex("03000070de065fede0093144eee2fe0e0000000000000000000000000000001c");
+    let mut neg_g2_bytes = g2_bytes;
+    neg_g2_bytes[31] |= 0x80;
+    assert_eq!(g2.to_bytes(), g2_bytes);
+    assert_eq!((-g2).to_bytes(), neg_g2_bytes);
+    assert_eq!(Point::from_bytes(&g2_bytes).unwrap(), g2);
+    assert_eq!(Point::from_bytes(&neg_g2_bytes).unwrap(), -g2);
+
+    // Random points round-trip.
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..100 {
+        let p = Point::random(&mut rng).to_affine();
+        assert_eq!(Affine::from_bytes(&p.to_bytes()).unwrap(), p);
+    }
+
+    // x = 2 is not the x-coordinate of any point, since 2^3 + 5 is not a square.
+    let mut bytes = Base::from(2).to_repr();
+    assert!(bool::from(Affine::from_bytes(&bytes).is_none()));
+    bytes[31] |= 0x80;
+    assert!(bool::from(Affine::from_bytes(&bytes).is_none()));
+
+    // The identity encoding with the sign bit set is invalid.
+    let mut bytes = [0; 32];
+    bytes[31] = 0x80;
+    assert!(bool::from(Affine::from_bytes(&bytes).is_none()));
+
+    // Non-canonical x-coordinates are rejected.
+    let mut bytes = (-Base::one()).to_repr();
+    bytes[0] += 1;
+    assert!(bool::from(Affine::from_bytes(&bytes).is_none()));
+}
+
+#[test]
+fn test_batch_normalize() {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut points = [Point::identity(); 16];
+    for (i, p) in points.iter_mut().enumerate() {
+        // Leave some identities in the input; they must be skipped.
+        if i % 5 != 0 {
+            *p = Point::random(&mut rng);
+        }
+    }
+
+    let mut affine = [Affine::default(); 16];
+    Point::batch_normalize(&points, &mut affine);
+    for (p, q) in points.iter().zip(affine.iter()) {
+        assert_eq!(p.to_affine(), *q);
+    }
+
+    // Converting back to projective round-trips, including the identities.
+    let projective: std::vec::Vec<Point> = affine.iter().map(|q| q.to_curve()).collect();
+    assert_eq!(projective[..], points[..]);
+
+    // All identities.
+    let points = [Point::identity(); 3];
+    let mut affine = [Affine::default(); 3];
+    Point::batch_normalize(&points, &mut affine);
+    assert!(affine.iter().all(|q| bool::from(q.is_identity())));
+}
+
+#[test]
+fn test_mul_glv() {
+    use crate::arithmetic::FieldExt;
+    use ff::Field;
+    use group::Group;
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let g = Point::generator();
+    for k in [
+        Scalar::zero(),
+        Scalar::one(),
+        -Scalar::one(),
+        Scalar::ZETA,
+        Point::GLV_A1,
+        Point::GLV_B2,
+    ]
+    .iter()
+    {
+        assert_eq!(g.mul_glv(k), g * k);
+    }
+    assert_eq!(Point::identity().mul_glv(&Scalar::one()), Point::identity());
+
+    for _ in 0..100 {
+        let p = Point::random(&mut rng);
+        let k = Scalar::random(&mut rng);
+        assert_eq!(p.mul_glv(&k), p * k);
+    }
+}
+
+#[test]
+fn test_point_conditional_selection() {
+    use group::{Curve, Group};
+    use rand::SeedableRng;
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let a = Point::random(&mut rng);
+    let b = Point::random(&mut rng);
+    assert_eq!(Point::conditional_select(&a, &b, Choice::from(0u8)), a);
+    assert_eq!(Point::conditional_select(&a, &b, Choice::from(1u8)), b);
+
+    let (a_affine, b_affine) = (a.to_affine(), b.to_affine());
+    assert_eq!(
+        Affine::conditional_select(&a_affine, &b_affine, Choice::from(0u8)),
+        a_affine
+    );
+    assert_eq!(
+        Affine::conditional_select(&a_affine, &b_affine, Choice::from(1u8)),
+        b_affine
+    );
+
+    // ct_eq compares points, not their projective representations.
+    let a2 = a.double() - a;
+    let identity = Point::identity();
+    let identity2 = a2 - a;
+    for (x, y) in [
+        (a, a),
+        (a, a2),
+        (a, b),
+        (a, identity),
+        (identity, identity2),
+    ]
+    .iter()
+    {
+        assert_eq!(bool::from(x.ct_eq(y)), x == y);
+        assert_eq!(
+            bool::from(x.to_affine().ct_eq(&y.to_affine())),
+            x.to_affine() == y.to_affine()
+        );
+    }
+    assert!(bool::from(a.ct_eq(&a2)));
+    assert!(bool::from(identity.ct_eq(&identity2)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_on_curve() {
+    use crate::arithmetic::{CurveAffine, CurveExt};
+    use ff::Field;
+    use group::{cofactor::CofactorGroup, prime::PrimeCurveAffine, Curve, Group};
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let g = Affine::generator();
+    let coords = g.coordinates().unwrap();
+    let (x, y) = (*coords.x(), *coords.y());
+
+    // On-curve coordinates are accepted.
+    assert_eq!(Affine::from_xy(x, y).unwrap(), g);
+    assert_eq!(Affine::from_xy(x, -y).unwrap(), -g);
+    assert!(bool::from(
+        Affine::from_xy(Base::zero(), Base::zero())
+            .unwrap()
+            .is_identity()
+    ));
+
+    // Off-curve coordinates are rejected, unless explicitly unchecked.
+    assert!(bool::from(Affine::from_xy(x, y + Base::one()).is_none()));
+    assert!(!bool::from(
+        Affine::from_xy_unchecked(x, y + Base::one()).is_on_curve()
+    ));
+    assert_eq!(Affine::from_xy_unchecked(x, y), g);
+    assert!(bool::from(
+        Affine::from_xy(Base::from(2), Base::zero()).is_none()
+    ));
+    assert!(bool::from(
+        Affine::from_xy(Base::zero(), Base::one()).is_none()
+    ));
+
+    // The same holds for Jacobian coordinates (x * z^2, y * z^3, z).
+    let z = Base::random(&mut rng);
+    let (z2, z3) = (z.square(), z.square() * z);
+    assert_eq!(
+        Point::new_jacobian(x * z2, y * z3, z).unwrap(),
+        g.to_curve()
+    );
+    assert!(bool::from(
+        Point::new_jacobian(x * z2, (y + Base::one()) * z3, z).is_none()
+    ));
+
+    for _ in 0..10 {
+        let point = Point::random(&mut rng);
+        assert!(bool::from(point.is_on_curve()));
+        assert!(bool::from(point.to_affine().is_on_curve()));
+
+        // The group has prime order, so every point on the curve is in the subgroup.
+        assert!(bool::from(point.is_torsion_free()));
+    }
+}
+
+#[test]
+fn test_cofactor() {
+    use group::{cofactor::CofactorGroup, Curve, Group, GroupEncoding};
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(Point::identity().clear_cofactor(), Point::identity());
+    for _ in 0..10 {
+        let point = Point::random(&mut rng);
+        assert_eq!(point.clear_cofactor(), point);
+
+        // Every successfully decoded point is in the prime-order group.
+        let decoded = Point::from_bytes(&point.to_affine().to_bytes()).unwrap();
+        assert!(bool::from(decoded.is_torsion_free()));
+        assert!(bool::from(decoded.into_subgroup().is_some()));
+    }
+}
+
+#[test]
+fn test_double() {
+    use group::Group;
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(Point::identity().double(), Point::identity());
+    for _ in 0..100 {
+        let point = Point::random(&mut rng);
+        assert_eq!(point.double(), point + point);
+        assert_eq!(point.double().double(), point * Scalar::from(4));
+    }
+}
+
+#[test]
+fn test_neg() {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert_eq!(-Point::identity(), Point::identity());
+    assert_eq!(-Affine::identity(), Affine::identity());
+    for _ in 0..100 {
+        let point = Point::random(&mut rng);
+        let affine = point.to_affine();
+        assert!(bool::from((point + (-point)).is_identity()));
+        assert!(bool::from((affine + (-affine)).is_identity()));
+        assert_eq!((-point).to_affine(), -affine);
+        assert_eq!(-(-point), point);
+        assert_eq!(-point, point * -Scalar::one());
+    }
+}
+
+#[test]
+fn test_identity() {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert!(bool::from(Point::identity().is_identity()));
+    assert!(bool::from(Affine::identity().is_identity()));
+    assert!(bool::from(Point::identity().to_affine().is_identity()));
+    assert!(bool::from(Affine::identity().to_curve().is_identity()));
+    assert!(!bool::from(Point::generator().is_identity()));
+    assert!(!bool::from(Affine::generator().is_identity()));
+
+    for _ in 0..10 {
+        let p = Point::random(&mut rng);
+        let q = p.to_affine();
+        assert_eq!(p + Point::identity(), p);
+        assert_eq!(p + Affine::identity(), p);
+        assert_eq!(q + Affine::identity(), p);
+        assert_eq!(Point::identity() + q, p);
+        assert!(bool::from((p + (-q)).is_identity()));
+        assert!(bool::from((p - q).to_affine().is_identity()));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_wnaf() {
+    use ff::Field;
+    use group::{Group, Wnaf, WnafBase, WnafScalar};
+    use rand::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let base = Point::random(&mut rng);
+    let scalars: std::vec::Vec<_> = (0..20)
+        .map(|_| Scalar::random(&mut rng))
+        .chain([Scalar::zero(), Scalar::one(), -Scalar::one()])
+        .collect();
+
+    // A fixed base with many scalars, using the recommended window size.
+    let mut wnaf = Wnaf::new();
+    let mut wnaf_base = wnaf.base(base, scalars.len());
+    for k in scalars.iter() {
+        assert_eq!(wnaf_base.scalar(k), base * k);
+    }
+
+    // A fixed scalar with many bases.
+    let mut wnaf = Wnaf::new();
+    let mut wnaf_scalar = wnaf.scalar(&scalars[0]);
+    for _ in 0..5 {
+        let base = Point::random(&mut rng);
+        assert_eq!(wnaf_scalar.base(base), base * scalars[0]);
+    }
+
+    // Cached precomputations of both.
+    let wnaf_base = WnafBase::<_, 5>::new(base);
+    for k in scalars.iter() {
+        assert_eq!(&wnaf_base * &WnafScalar::new(k), base * k);
+    }
+}